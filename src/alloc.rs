@@ -0,0 +1,111 @@
+//! Counting heap allocations, to check that allocation-avoiding code like day 7's `SmallVec`s
+//! actually avoids them
+//!
+//! Nothing is counted unless [`Counting`] is installed as the global allocator, which the `aoc`
+//! binary does when built with the `count-allocations` feature...
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: aoc::alloc::Counting = aoc::alloc::Counting;
+//! ```
+//!
+//! The counters are shared by the whole process, so a measurement includes anything other threads
+//! allocate at the same time.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+/// Bytes currently allocated, and the most there have been since the last [`measure`] began
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator with counters on the side
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Counting;
+
+impl Counting {
+    fn allocated(size: usize) {
+        INSTALLED.store(true, Ordering::Relaxed);
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size, Ordering::Relaxed);
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn freed(size: usize) {
+        CURRENT.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::freed(layout.size());
+    }
+
+    /// Growing or shrinking counts as one more allocation of the new size
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::freed(layout.size());
+            Self::allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// The heap used by one measured step
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// How many times memory was allocated or reallocated
+    pub allocations: usize,
+    /// Total bytes asked for, including memory that was freed again
+    pub bytes: usize,
+    /// The most bytes held at once, on top of what was already held before the step
+    pub peak: usize,
+}
+
+/// Whether [`Counting`] is the global allocator, only known once it's allocated something
+pub fn installed() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+/// Run `f` and count what it allocates, or just run it when allocations aren't being counted
+///
+/// Measurements don't nest, the peak of an outer one is lost when an inner one begins.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !installed() {
+        return (f(), None);
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let start = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(start, Ordering::Relaxed);
+
+    let value = f();
+
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(start),
+    };
+    (value, Some(usage))
+}
@@ -0,0 +1,172 @@
+//! Known correct answers, kept next to the inputs in `input/NN/answers.toml`
+//!
+//! Only the tiny subset of TOML in [`crate::toml`] is understood, which looks like...
+//!
+//! ```toml
+//! [example]
+//! part1 = 95437
+//! part2 = "24933642"
+//!
+//! [examples.second]
+//! part1 = 1234
+//! ```
+//!
+//! Where `[example]` is for `example.txt` and `[examples.<name>]` is for `examples/<name>.txt`.
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+use crate::error::in_file;
+use crate::input::{day_dir, Source};
+use crate::toml;
+use crate::{Answer, ParseError, Part, Result};
+
+pub const FILE_NAME: &str = "answers.toml";
+
+/// Expected answers for one day, keyed by input section then part
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    sections: toml::Table,
+}
+
+impl Answers {
+    /// Load the answers for a day, a missing file just means nothing is known yet
+    pub fn load(dir: &Path, day: u8) -> Result<Self> {
+        let path = day_dir(dir, day).join(FILE_NAME);
+        match std::fs::read_to_string(&path) {
+            Ok(text) => text
+                .parse()
+                .map_err(|err: ParseError| err.with_file(Some(&path)).into()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(in_file(err, &path).into()),
+        }
+    }
+
+    /// The expected answer for a part of the given input, if it's been recorded
+    pub fn expected(&self, source: &Source, part: Part) -> Option<&str> {
+        self.sections
+            .get(&section(source)?)?
+            .get(key(part))
+            .map(String::as_str)
+    }
+
+    /// Add an answer to the manifest file unless one is already recorded, keeping everything
+    /// else in the file as it was. Gives whether it was added.
+    pub fn record(
+        &mut self,
+        dir: &Path,
+        day: u8,
+        source: &Source,
+        part: Part,
+        answer: &str,
+    ) -> Result<bool> {
+        let Some(section) = section(source) else {
+            return Ok(false);
+        };
+        if self.expected(source, part).is_some() {
+            return Ok(false);
+        }
+        let path = day_dir(dir, day).join(FILE_NAME);
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(in_file(err, &path).into()),
+        };
+        let value = if answer.parse::<i64>().is_ok() {
+            answer.to_string()
+        } else {
+            toml::quote(answer)
+        };
+        let entry = format!("{} = {value}", key(part));
+
+        let mut lines = text.lines().collect::<Vec<_>>();
+        let header = format!("[{section}]");
+        match lines.iter().position(|line| line.trim() == header) {
+            Some(start) => {
+                // After the last line of the section that isn't blank
+                let end = lines[start + 1..]
+                    .iter()
+                    .position(|line| line.trim().starts_with('['))
+                    .map_or(lines.len(), |end| start + 1 + end);
+                let at = (start + 1..end)
+                    .rev()
+                    .find(|&idx| !lines[idx].trim().is_empty())
+                    .map_or(start + 1, |idx| idx + 1);
+                lines.insert(at, &entry);
+            }
+            None => {
+                if lines.last().map_or(false, |line| !line.trim().is_empty()) {
+                    lines.push("");
+                }
+                lines.push(&header);
+                lines.push(&entry);
+            }
+        }
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&path, lines.join("\n") + "\n")?;
+        self.sections
+            .entry(section)
+            .or_default()
+            .insert(key(part).to_string(), answer.to_string());
+        Ok(true)
+    }
+
+    /// Compare an answer against the expected one
+    pub fn check(&self, source: &Source, part: Part, answer: &Answer) -> Check {
+        match self.expected(source, part) {
+            Some(expected) if expected == answer.to_string() => Check::Pass,
+            Some(expected) => Check::Fail(expected.to_string()),
+            None => Check::Unknown,
+        }
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> std::result::Result<Self, Self::Err> {
+        let mut sections = toml::parse(text)?;
+        if let Some((key, _)) = sections.remove("").and_then(|keys| keys.into_iter().next()) {
+            return Err(toml::key_error("Answers must be in a section", text, &key));
+        }
+        Ok(Self { sections })
+    }
+}
+
+/// Outcome of comparing an answer against the manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    /// Holds the expected answer
+    Fail(String),
+    /// Nothing recorded for this input and part
+    Unknown,
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail(expected) => write!(f, "FAIL (expected {expected})"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Explicit files and stdin can't be matched to a section
+fn section(source: &Source) -> Option<String> {
+    match source {
+        Source::Example => Some("example".to_string()),
+        Source::NamedExample(name) => Some(format!("examples.{name}")),
+        Source::Real => Some("real".to_string()),
+        Source::File(_) | Source::Stdin => None,
+    }
+}
+
+fn key(part: Part) -> &'static str {
+    match part {
+        Part::One => "part1",
+        Part::Two => "part2",
+    }
+}
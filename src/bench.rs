@@ -0,0 +1,108 @@
+//! Repeated timing of the parse and solve phases, no external framework needed
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+use crate::alloc::{self, Usage};
+use crate::solution::{DynSolution, Result};
+use crate::Part;
+
+/// A timed step of running a solution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// Turning the input text into the day's model, e.g. building day 7's `Fs`
+    Parse,
+    Solve(Part),
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Solve(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarise a set of timings, there must be at least one
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "Can't summarise zero samples");
+        samples.sort();
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Timings for one phase across every iteration
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub phase: Phase,
+    pub stats: Stats,
+    /// Heap usage of the phase in the last iteration, when allocations are being counted
+    pub alloc: Option<Usage>,
+}
+
+/// Parse the input and solve each part `iterations` times, which must be at least once. Each part
+/// is solved against the input parsed in the same iteration so parsing never leaks into the solve
+/// timings.
+///
+/// The parts are solved with the named implementation when there is one, see
+/// [`DynSolution::solve_named`].
+pub fn bench(
+    solution: &dyn DynSolution,
+    implementation: Option<&str>,
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<Vec<Report>> {
+    assert!(iterations > 0, "Can't bench zero iterations");
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut part_samples = vec![Vec::with_capacity(iterations); parts.len()];
+    let mut parse_alloc = None;
+    let mut part_allocs = vec![None; parts.len()];
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let (parsed, usage) = alloc::measure(|| solution.parse(input));
+        parse_samples.push(start.elapsed());
+        let parsed = parsed?;
+        parse_alloc = usage;
+
+        for ((&part, samples), part_alloc) in parts
+            .iter()
+            .zip(part_samples.iter_mut())
+            .zip(part_allocs.iter_mut())
+        {
+            let start = Instant::now();
+            let (answer, usage) =
+                alloc::measure(|| solution.solve_named(part, implementation, &parsed));
+            samples.push(start.elapsed());
+            drop(answer?);
+            *part_alloc = usage;
+        }
+    }
+
+    let mut reports = vec![Report {
+        phase: Phase::Parse,
+        stats: Stats::from_samples(parse_samples),
+        alloc: parse_alloc,
+    }];
+    reports.extend(parts.iter().zip(part_samples).zip(part_allocs).map(
+        |((&part, samples), alloc)| Report {
+            phase: Phase::Solve(part),
+            stats: Stats::from_samples(samples),
+            alloc,
+        },
+    ));
+    Ok(reports)
+}
@@ -0,0 +1,783 @@
+//! Single entry point for every day, try `cargo run -- --help`
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc::alloc::Usage;
+use aoc::answers::{self, Answers, Check};
+use aoc::bench::{self, Phase};
+use aoc::config::{Config, ConfigFile};
+use aoc::extract::Page;
+use aoc::gen::Rng;
+use aoc::input::InputDir;
+use aoc::remote::{self, Fetched, Submitted};
+use aoc::report::{Format, JsonObject};
+use aoc::watch::Watcher;
+use aoc::{input, solution, Parser, Part, Parts, Source};
+use clap::Subcommand;
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: aoc::alloc::Counting = aoc::alloc::Counting;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[derive(Parser, Debug)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve one part of a day's puzzle
+    Run {
+        /// Day number, e.g. 7
+        day: u8,
+
+        /// Compare the answer against `answers.toml` and fail if it doesn't match
+        #[arg(long)]
+        check: bool,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        #[command(flatten)]
+        args: aoc::Args,
+    },
+    /// Time the parse and solve phases of a day over many iterations
+    Bench {
+        /// Day number, e.g. 7
+        day: u8,
+
+        /// How many times to repeat each phase
+        #[arg(
+            short = 'n',
+            long,
+            default_value_t = 100,
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+        )]
+        iterations: usize,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        #[command(flatten)]
+        args: aoc::Args,
+    },
+    /// Re-run a day whenever its input or answers change, showing what changed
+    Watch {
+        /// Day number, e.g. 7
+        day: u8,
+
+        /// How often to look for changes, in milliseconds
+        #[arg(long, value_name = "MS", default_value_t = 500)]
+        interval: u64,
+
+        #[command(flatten)]
+        args: aoc::Args,
+    },
+    /// Run both parts of every day on the example and real inputs, checking the answers
+    RunAll {
+        #[command(flatten)]
+        input_dir: InputDir,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Show where every day of the calendar is up to, running the checks for each solved day
+    Status {
+        #[command(flatten)]
+        input_dir: InputDir,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// List every day that has a solution, with the names of any alternative implementations
+    List,
+    /// Solve with every implementation of each part and report any that disagree
+    Compare {
+        /// Day number, or every day when left out
+        day: Option<u8>,
+
+        #[command(flatten)]
+        input_dir: InputDir,
+
+        /// Also compare on this many generated inputs, seeded 0, 1, 2 and so on
+        #[arg(long, value_name = "ROUNDS", default_value_t = 0)]
+        fuzz: u64,
+
+        /// Largest size of the generated inputs, they cycle through every size up to this
+        #[arg(long, default_value_t = 20)]
+        size: usize,
+    },
+    /// Print a random input for a day, the same seed and size always give the same input
+    Gen {
+        /// Day number, e.g. 7
+        day: u8,
+
+        /// Seed for the random numbers
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Roughly how many of the day's things to generate, like elves, moves or trees across
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+    },
+    /// Create the module, inputs, answers and test for a new day and register it
+    New {
+        /// Day number, e.g. 9
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The puzzle's title
+        #[arg(long, default_value = "TODO")]
+        title: String,
+
+        /// Root of the crate to add the day to
+        #[arg(long, value_name = "DIR", default_value = ".")]
+        root: PathBuf,
+    },
+    /// Download a day's real input, unless it's already been downloaded
+    Fetch {
+        /// Day number, e.g. 7
+        day: u8,
+
+        #[command(flatten)]
+        input_dir: InputDir,
+
+        #[command(flatten)]
+        config: ConfigFile,
+    },
+    /// Pull the examples, answers and description out of a saved puzzle page
+    Extract {
+        /// Day number, e.g. 7
+        day: u8,
+
+        /// The puzzle page, saved from a browser
+        html: PathBuf,
+
+        #[command(flatten)]
+        input_dir: InputDir,
+    },
+    /// Solve a part on the real input and submit the answer, unless it's known to be wrong
+    Submit {
+        /// Day number, e.g. 7
+        day: u8,
+
+        /// Which part the answer is for
+        #[arg(value_enum)]
+        part: Part,
+
+        #[command(flatten)]
+        input_dir: InputDir,
+
+        #[command(flatten)]
+        config: ConfigFile,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run {
+            day,
+            check,
+            format,
+            args,
+        } => run(day, check, format, &args),
+        Command::Bench {
+            day,
+            iterations,
+            format,
+            args,
+        } => run_bench(day, iterations, format, &args),
+        Command::Watch {
+            day,
+            interval,
+            args,
+        } => watch(day, Duration::from_millis(interval), &args),
+        Command::RunAll { input_dir, format } => run_all(&input_dir.path, format),
+        Command::Status { input_dir, format } => status(&input_dir.path, format),
+        Command::List => {
+            for puzzle in solution::registry() {
+                let mut names = puzzle.solution.implementations(Part::One);
+                for name in puzzle.solution.implementations(Part::Two) {
+                    if !names.contains(&name) {
+                        names.push(name);
+                    }
+                }
+                if names.is_empty() {
+                    println!("{:02} {}", puzzle.day, puzzle.title);
+                } else {
+                    println!("{:02} {} [{}]", puzzle.day, puzzle.title, names.join(", "));
+                }
+            }
+            Ok(())
+        }
+        Command::Compare {
+            day,
+            input_dir,
+            fuzz,
+            size,
+        } => compare(day, &input_dir.path, fuzz, size),
+        Command::Gen { day, seed, size } => gen(day, seed, size),
+        Command::New { day, title, root } => new_day(day, &title, &root),
+        Command::Fetch {
+            day,
+            input_dir,
+            config,
+        } => fetch(day, &input_dir.path, &config.path),
+        Command::Extract {
+            day,
+            html,
+            input_dir,
+        } => extract(day, &html, &input_dir.path),
+        Command::Submit {
+            day,
+            part,
+            input_dir,
+            config,
+        } => submit(day, part, &input_dir.path, &config.path),
+    };
+    if let Err(err) = result {
+        eprintln!("Error: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn find(day: u8) -> Result<&'static solution::Puzzle> {
+    Ok(solution::find(day).ok_or_else(|| format!("No solution for day {day}"))?)
+}
+
+/// The fields every JSON answer record starts with
+fn json_answer(day: u8, outcome: &solution::Outcome, source: &Source) -> JsonObject {
+    let json = JsonObject::new()
+        .num("day", day)
+        .str("part", &outcome.part.to_string())
+        .str("input", &source.to_string())
+        .str("answer", &outcome.answer.to_string())
+        .nanos("elapsed_ns", outcome.elapsed);
+    json_alloc(json, "", outcome.alloc)
+}
+
+/// Heap usage fields, prefixed by `prefix`, when allocations are being counted
+fn json_alloc(json: JsonObject, prefix: &str, alloc: Option<Usage>) -> JsonObject {
+    match alloc {
+        Some(usage) => json
+            .num(&format!("{prefix}allocations"), usage.allocations as u128)
+            .num(&format!("{prefix}alloc_bytes"), usage.bytes as u128)
+            .num(&format!("{prefix}peak_bytes"), usage.peak as u128),
+        None => json,
+    }
+}
+
+/// How long parsing took and what it allocated, which every part solved from one parse shares
+fn json_parse(json: JsonObject, run: &solution::Run) -> JsonObject {
+    let json = json.nanos("parse_ns", run.parse_elapsed);
+    json_alloc(json, "parse_", run.parse_alloc)
+}
+
+fn json_check(json: JsonObject, check: &Check) -> JsonObject {
+    match check {
+        Check::Pass => json.str("check", "pass"),
+        Check::Fail(expected) => json.str("check", "fail").str("expected", expected),
+        Check::Unknown => json.str("check", "unknown"),
+    }
+}
+
+/// A row saying why a day, or one of its inputs, couldn't be run
+fn print_error(format: Format, day: u8, source: Option<&Source>, err: &dyn std::fmt::Display) {
+    match (format, source) {
+        (Format::Text, Some(source)) => {
+            println!("{day:02}   {:<10} error: {err}", source.to_string())
+        }
+        (Format::Text, None) => println!("{day:02}   error: {err}"),
+        (Format::Json, _) => {
+            let json = JsonObject::new().num("day", day);
+            let json = match source {
+                Some(source) => json.str("input", &source.to_string()),
+                None => json,
+            };
+            println!("{}", json.str("error", &err.to_string()).finish());
+        }
+    }
+}
+
+fn run(day: u8, check: bool, format: Format, args: &aoc::Args) -> Result<()> {
+    aoc::trace::set_level(args.verbosity);
+    let puzzle = find(day)?;
+    let input = args.read_input(day)?;
+    let source = args.source();
+    let run = puzzle
+        .run_with(
+            &input,
+            args.parts(Parts::One),
+            args.implementation.as_deref(),
+        )
+        .map_err(|err| err.with_file(source.path(&args.input_dir.path, day).as_deref()))?;
+    let answers = if check {
+        Some(Answers::load(&args.input_dir.path, day)?)
+    } else {
+        None
+    };
+
+    let mut passed = true;
+    for outcome in &run.outcomes {
+        let check = answers
+            .as_ref()
+            .map(|answers| answers.check(&source, outcome.part, &outcome.answer));
+
+        match format {
+            Format::Text => {
+                println!("{}", outcome.answer);
+                match &check {
+                    Some(Check::Unknown) => eprintln!(
+                        "Day {day:02} part {} ({source}): no answer recorded to check against",
+                        outcome.part
+                    ),
+                    Some(check) => {
+                        eprintln!("Day {day:02} part {} ({source}): {check}", outcome.part)
+                    }
+                    None => {}
+                }
+            }
+            Format::Json => {
+                let mut json = json_parse(json_answer(day, outcome, &source), &run);
+                if let Some(check) = &check {
+                    json = json_check(json, check);
+                }
+                println!("{}", json.finish());
+            }
+        }
+        passed &= !matches!(check, Some(Check::Fail(_)));
+    }
+    if !passed {
+        return Err("Answer check did not pass".into());
+    }
+    Ok(())
+}
+
+fn watch(day: u8, interval: Duration, args: &aoc::Args) -> Result<()> {
+    aoc::trace::set_level(args.verbosity);
+    let puzzle = find(day)?;
+    let source = args.source();
+    let Some(input_path) = source.path(&args.input_dir.path, day) else {
+        return Err("Can't watch stdin, save the input to a file and use --input".into());
+    };
+    let answers_path = input::day_dir(&args.input_dir.path, day).join(answers::FILE_NAME);
+    let mut watcher = Watcher::new([input_path, answers_path]);
+    for path in watcher.paths() {
+        eprintln!("Watching {}", path.display());
+    }
+
+    let mut previous = Vec::new();
+    loop {
+        match watch_run(puzzle, args, &previous) {
+            Ok(outcomes) => previous = outcomes,
+            Err(err) => eprintln!("Error: {err}"),
+        }
+        while watcher.changed().is_empty() {
+            std::thread::sleep(interval);
+        }
+    }
+}
+
+/// One run for `watch`, comparing each part with the previous run
+fn watch_run(
+    puzzle: &solution::Puzzle,
+    args: &aoc::Args,
+    previous: &[solution::Outcome],
+) -> Result<Vec<solution::Outcome>> {
+    let day = puzzle.day;
+    let source = args.source();
+    let input = args.read_input(day)?;
+    let run = puzzle
+        .run_with(
+            &input,
+            args.parts(Parts::Both),
+            args.implementation.as_deref(),
+        )
+        .map_err(|err| err.with_file(source.path(&args.input_dir.path, day).as_deref()))?;
+    let answers = Answers::load(&args.input_dir.path, day)?;
+
+    println!(
+        "--- Day {day:02} ({source}), parsed in {:.2?}",
+        run.parse_elapsed
+    );
+    for outcome in &run.outcomes {
+        let before = previous.iter().find(|before| before.part == outcome.part);
+        let answer = match before {
+            Some(before) if before.answer == outcome.answer => {
+                format!("{} (unchanged)", outcome.answer)
+            }
+            Some(before) => format!("{} (was {})", outcome.answer, before.answer),
+            None => outcome.answer.to_string(),
+        };
+        let elapsed = match before {
+            Some(before) => format!("{:.2?}, was {:.2?}", outcome.elapsed, before.elapsed),
+            None => format!("{:.2?}", outcome.elapsed),
+        };
+        let check = match answers.check(&source, outcome.part, &outcome.answer) {
+            Check::Unknown => String::new(),
+            check => format!(" {check}"),
+        };
+        println!("part {}: {answer} in {elapsed}{check}", outcome.part);
+    }
+    Ok(run.outcomes)
+}
+
+/// Every registered day against its example and real inputs, skipping inputs that don't exist
+fn run_all(input_dir: &Path, format: Format) -> Result<()> {
+    if format == Format::Text {
+        println!(
+            "{:<4} {:<10} {:>10} {:<5} {:<20} {:>10}  check",
+            "day", "input", "parse", "part", "answer", "solve"
+        );
+    }
+    let mut failures = 0;
+    for puzzle in solution::registry() {
+        let day = puzzle.day;
+        // One day's broken answers or examples shouldn't stop the days after it from running
+        let loaded = Answers::load(input_dir, day)
+            .and_then(|answers| Ok((answers, input::examples(input_dir, day)?)));
+        let (answers, mut sources) = match loaded {
+            Ok(loaded) => loaded,
+            Err(err) => {
+                failures += 1;
+                print_error(format, day, None, &err);
+                continue;
+            }
+        };
+        sources.push(Source::Real);
+        for source in sources {
+            let run = match source.read(input_dir, day) {
+                Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                input => input
+                    .map_err(aoc::Error::from)
+                    .and_then(|input| puzzle.run(&input, Parts::Both.parts()))
+                    .map_err(|err| err.with_file(source.path(input_dir, day).as_deref())),
+            };
+            match run {
+                Ok(run) => {
+                    for outcome in &run.outcomes {
+                        let check = answers.check(&source, outcome.part, &outcome.answer);
+                        if let Check::Fail(_) = check {
+                            failures += 1;
+                        }
+                        match format {
+                            Format::Text => println!(
+                                "{day:02}   {:<10} {:>10.2?} {:<5} {:<20} {:>10.2?}  {check}",
+                                source.to_string(),
+                                run.parse_elapsed,
+                                outcome.part,
+                                outcome.answer.to_string(),
+                                outcome.elapsed,
+                            ),
+                            Format::Json => {
+                                let json = json_parse(json_answer(day, outcome, &source), &run);
+                                println!("{}", json_check(json, &check).finish());
+                            }
+                        }
+                    }
+                }
+                Err(err) => {
+                    failures += 1;
+                    print_error(format, day, Some(&source), &err);
+                }
+            }
+        }
+    }
+    if failures > 0 {
+        return Err(format!("{failures} answer(s) did not pass").into());
+    }
+    Ok(())
+}
+
+/// A line per day of the calendar, with a count of the finished days at the end
+fn status(input_dir: &Path, format: Format) -> Result<()> {
+    if format == Format::Text {
+        println!(
+            "{:<4} {:<26} {:>7} {:>4} {:>7}  {:<16} {:>10}",
+            "day", "title", "example", "real", "answers", "checks", "time"
+        );
+    }
+    let mut done = 0;
+    for day in aoc::status::DAYS {
+        // One day's broken answers shouldn't hide the rest of the calendar
+        let status = match aoc::status::status(input_dir, day) {
+            Ok(status) => status,
+            Err(err) => {
+                print_error(format, day, None, &err);
+                continue;
+            }
+        };
+        if status.done() {
+            done += 1;
+        }
+        match format {
+            Format::Text => {
+                let checks = match (status.passed, status.failed) {
+                    (0, 0) => "-".to_string(),
+                    (passed, 0) => format!("{passed} pass"),
+                    (passed, failed) => format!("{failed} FAIL, {passed} pass"),
+                };
+                let elapsed = status
+                    .elapsed
+                    .map_or_else(|| "-".to_string(), |elapsed| format!("{elapsed:.2?}"));
+                let error = status
+                    .error
+                    .map_or_else(String::new, |err| format!("  error: {err}"));
+                println!(
+                    "{day:02}   {:<26} {:>7} {:>4} {:>7}  {checks:<16} {elapsed:>10}{error}",
+                    status.title.unwrap_or("-"),
+                    status.examples,
+                    if status.real { "yes" } else { "-" },
+                    status.answers,
+                )
+            }
+            Format::Json => {
+                let json = JsonObject::new().num("day", day);
+                let json = match status.title {
+                    Some(title) => json.str("title", title),
+                    None => json.null("title"),
+                };
+                let json = json
+                    .num("examples", status.examples as u128)
+                    .bool("real", status.real)
+                    .num("answers", status.answers as u128)
+                    .num("passed", status.passed as u128)
+                    .num("failed", status.failed as u128);
+                let json = match status.elapsed {
+                    Some(elapsed) => json.nanos("elapsed_ns", elapsed),
+                    None => json.null("elapsed_ns"),
+                };
+                let json = match &status.error {
+                    Some(err) => json.str("error", err),
+                    None => json.null("error"),
+                };
+                println!("{}", json.finish());
+            }
+        }
+    }
+    if format == Format::Text {
+        println!("{done} of {} days done", aoc::status::DAYS.count());
+    }
+    Ok(())
+}
+
+/// Every implementation of each part against the example and real inputs of one day or all of them,
+/// and then some generated inputs
+fn compare(day: Option<u8>, input_dir: &Path, fuzz: u64, size: usize) -> Result<()> {
+    let puzzles = match day {
+        Some(day) => vec![find(day)?],
+        None => solution::registry().iter().collect(),
+    };
+    let mut disagreements = 0;
+    let mut failed = 0;
+    for puzzle in puzzles {
+        let day = puzzle.day;
+        let mut sources = input::examples(input_dir, day)?;
+        sources.push(Source::Real);
+        for source in sources {
+            let comparisons = match source.read(input_dir, day) {
+                Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                input => input
+                    .map_err(aoc::Error::from)
+                    .and_then(|input| puzzle.compare(&input))
+                    .map_err(|err| err.with_file(source.path(input_dir, day).as_deref())),
+            };
+            // A bad input is reported like a disagreement, so the rest still get compared
+            let comparisons = match comparisons {
+                Ok(comparisons) => comparisons,
+                Err(err) => {
+                    failed += 1;
+                    print_error(Format::Text, day, Some(&source), &err);
+                    continue;
+                }
+            };
+            for comparison in comparisons {
+                let verdict = if comparison.agrees() {
+                    "agree"
+                } else {
+                    disagreements += 1;
+                    "DISAGREE"
+                };
+                let answers = comparison
+                    .answers
+                    .iter()
+                    .map(|(name, answer)| match answer {
+                        Ok(answer) => format!("{name}={answer}"),
+                        Err(err) => format!("{name}=({err})"),
+                    })
+                    .collect::<Vec<_>>();
+                println!(
+                    "{day:02}   {:<10} part {}  {verdict:<8}  {}",
+                    source.to_string(),
+                    comparison.part,
+                    answers.join(" ")
+                );
+            }
+        }
+        let has_implementations = [Part::One, Part::Two]
+            .into_iter()
+            .any(|part| !puzzle.solution.implementations(part).is_empty());
+        if fuzz > 0 && has_implementations {
+            disagreements += fuzz_compare(puzzle, fuzz, size)?;
+        }
+    }
+    if disagreements > 0 {
+        return Err(format!("{disagreements} comparison(s) disagreed").into());
+    }
+    if failed > 0 {
+        return Err(format!("{failed} input(s) couldn't be read or parsed").into());
+    }
+    Ok(())
+}
+
+/// Compare on generated inputs, only showing the ones that disagree, giving how many did
+fn fuzz_compare(puzzle: &solution::Puzzle, rounds: u64, max_size: usize) -> Result<usize> {
+    let day = puzzle.day;
+    let mut disagreements = 0;
+    for seed in 0..rounds {
+        let size = (seed % (max_size as u64 + 1)) as usize;
+        let input = puzzle.solution.generate(&mut Rng::new(seed), size)?;
+        for comparison in puzzle.compare(&input)? {
+            if !comparison.agrees() {
+                disagreements += 1;
+                println!(
+                    "{day:02}   seed {seed}, size {size}, part {}  DISAGREE  {:?}",
+                    comparison.part, comparison.answers
+                );
+            }
+        }
+    }
+    println!("{day:02}   {rounds} generated inputs, {disagreements} disagreement(s)");
+    Ok(disagreements)
+}
+
+fn gen(day: u8, seed: u64, size: usize) -> Result<()> {
+    let input = find(day)?.solution.generate(&mut Rng::new(seed), size)?;
+    print!("{input}");
+    Ok(())
+}
+
+fn new_day(day: u8, title: &str, root: &Path) -> Result<()> {
+    for path in aoc::scaffold::new_day(root, day, title)? {
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+fn fetch(day: u8, input_dir: &Path, config: &Path) -> Result<()> {
+    let config = Config::load(config)?;
+    match remote::fetch(&config, input_dir, day)? {
+        Fetched::Cached(path) => println!("Already have {}", path.display()),
+        Fetched::Downloaded(path) => println!("Downloaded {}", path.display()),
+    }
+    Ok(())
+}
+
+fn extract(day: u8, html: &Path, input_dir: &Path) -> Result<()> {
+    let html = std::fs::read_to_string(html).map_err(|err| format!("{}: {err}", html.display()))?;
+    let page = Page::parse(&html);
+    if let Some(title) = &page.title {
+        println!("Day {day:02}: {title}");
+    }
+    for done in aoc::extract::extract(&page, input_dir, day)? {
+        println!("{done}");
+    }
+    Ok(())
+}
+
+fn submit(day: u8, part: Part, input_dir: &Path, config: &Path) -> Result<()> {
+    let puzzle = find(day)?;
+    let source = Source::Real;
+    let input = source.read(input_dir, day)?;
+    let run = puzzle
+        .run(&input, &[part])
+        .map_err(|err| err.with_file(source.path(input_dir, day).as_deref()))?;
+    let config = Config::load(config)?;
+    for outcome in run.outcomes {
+        println!("Day {day:02} part {part}: {}", outcome.answer);
+        match remote::submit(&config, input_dir, day, part, &outcome.answer)? {
+            Submitted::Judged(verdict) => println!("{verdict}"),
+            Submitted::Refused(reason) => return Err(format!("Not submitting, {reason}").into()),
+        }
+    }
+    Ok(())
+}
+
+fn run_bench(day: u8, iterations: usize, format: Format, args: &aoc::Args) -> Result<()> {
+    aoc::trace::set_level(args.verbosity);
+    let puzzle = find(day)?;
+    let input = args.read_input(day)?;
+    let source = args.source();
+    let reports = bench::bench(
+        puzzle.solution,
+        args.implementation.as_deref(),
+        &input,
+        args.parts(Parts::Both),
+        iterations,
+    )
+    .map_err(|err| err.with_file(source.path(&args.input_dir.path, day).as_deref()))?;
+
+    let counted = reports.iter().any(|report| report.alloc.is_some());
+    if format == Format::Text {
+        match &args.implementation {
+            Some(name) => println!("Day {day:02} ({source}), {name}, {iterations} iterations"),
+            None => println!("Day {day:02} ({source}), {iterations} iterations"),
+        }
+        print!(
+            "{:<8} {:>12} {:>12} {:>12}",
+            "phase", "min", "median", "max"
+        );
+        if counted {
+            print!(" {:>10} {:>12} {:>12}", "allocs", "bytes", "peak");
+        }
+        println!();
+    }
+    for report in reports {
+        let stats = report.stats;
+        match format {
+            Format::Text => {
+                print!(
+                    "{:<8} {:>12.2?} {:>12.2?} {:>12.2?}",
+                    report.phase.to_string(),
+                    stats.min,
+                    stats.median,
+                    stats.max
+                );
+                if let Some(usage) = report.alloc {
+                    print!(
+                        " {:>10} {:>12} {:>12}",
+                        usage.allocations, usage.bytes, usage.peak
+                    );
+                }
+                println!();
+            }
+            Format::Json => {
+                let part = match report.phase {
+                    Phase::Parse => "parse".to_string(),
+                    Phase::Solve(part) => part.to_string(),
+                };
+                let json = JsonObject::new()
+                    .num("day", day)
+                    .str("part", &part)
+                    .str("input", &source.to_string())
+                    .null("answer")
+                    .nanos("elapsed_ns", stats.median)
+                    .nanos("min_ns", stats.min)
+                    .nanos("max_ns", stats.max)
+                    .num("iterations", iterations as u128);
+                println!("{}", json_alloc(json, "", report.alloc).finish());
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,4 +0,0 @@
-fn main() {
-    println!("Advent of Code. Execute each day by running: cargo run --bin dayXX");
-    println!("For parameter information try: cargo run --bin dayXX -- --help");
-}
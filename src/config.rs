@@ -0,0 +1,94 @@
+//! Settings for talking to the puzzle site, from `aoc.toml` and the environment
+//!
+//! ```toml
+//! session = "53616c7465645f5f..."
+//! endpoint = "https://adventofcode.com/2022"
+//! ```
+//!
+//! `AOC_SESSION` and `AOC_ENDPOINT` take priority over the file. The session cookie is a secret,
+//! which is why `aoc.toml` is ignored by git.
+use std::path::{Path, PathBuf};
+
+use crate::error::in_file;
+use crate::{toml, Error, ParseError, Result};
+
+/// Where the config is looked for unless told otherwise
+pub const DEFAULT_FILE: &str = "aoc.toml";
+
+/// This year's puzzles on the real site
+pub const DEFAULT_ENDPOINT: &str = "https://adventofcode.com/2022";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// The `session` cookie of a logged in browser
+    pub session: Option<String>,
+    /// Base URL that `/day/N/input` and `/day/N/answer` are relative to
+    pub endpoint: String,
+    /// Where the settings were loaded from, or would have been if the file existed
+    pub file: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            endpoint: DEFAULT_ENDPOINT.to_string(),
+            file: PathBuf::from(DEFAULT_FILE),
+        }
+    }
+}
+
+/// The `--config` argument of the commands that talk to the puzzle site
+#[derive(clap::Args, Debug, Clone)]
+pub struct ConfigFile {
+    /// Settings file with the session cookie and endpoint
+    #[arg(id = "config", long, value_name = "PATH", default_value = DEFAULT_FILE)]
+    pub path: PathBuf,
+}
+
+impl Config {
+    /// Read the config file if there is one, then apply the environment on top
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut config = match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|err| err.with_file(Some(path)))?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(err) => return Err(in_file(err, path).into()),
+        };
+        config.file = path.to_path_buf();
+        if let Ok(session) = std::env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(endpoint) = std::env::var("AOC_ENDPOINT") {
+            config.endpoint = endpoint;
+        }
+        Ok(config)
+    }
+
+    fn parse(text: &str) -> std::result::Result<Self, ParseError> {
+        let mut config = Self::default();
+        let settings = toml::parse(text)?.remove("").unwrap_or_default();
+        for (key, value) in settings {
+            match key.as_str() {
+                "session" => config.session = Some(value),
+                "endpoint" => config.endpoint = value,
+                _ => return Err(toml::key_error("Unknown setting", text, &key)),
+            }
+        }
+        Ok(config)
+    }
+
+    /// The session cookie, or an error explaining how to provide one
+    pub fn session(&self) -> Result<&str> {
+        self.session.as_deref().ok_or_else(|| {
+            Error::Remote(format!(
+                "No session cookie, set AOC_SESSION or `session` in {}",
+                self.file.display()
+            ))
+        })
+    }
+
+    /// `path` relative to the endpoint
+    pub fn url(&self, path: &str) -> String {
+        format!("{}/{path}", self.endpoint.trim_end_matches('/'))
+    }
+}
@@ -0,0 +1,79 @@
+//! # Day 1: Calorie Counting
+use crate::gen::Rng;
+use crate::{parse, Answer, Result, Solution};
+
+/// Keeps the three largest totals, smallest first
+fn top_three(calories: &[i32]) -> [i32; 3] {
+    let mut max_calories = [0, 0, 0];
+    for &total in calories {
+        if max_calories[0] < total {
+            max_calories[0] = total;
+            max_calories.sort();
+        }
+    }
+    max_calories
+}
+
+/// Total calories carried by each elf
+pub fn parse(input: &str) -> Result<Vec<i32>> {
+    Ok(parse::groups(input)
+        .map(|elf| {
+            elf.iter()
+                .map(|line| line.whole().trim().integer::<i32>())
+                .sum::<std::result::Result<i32, _>>()
+        })
+        .collect::<std::result::Result<_, _>>()?)
+}
+
+/// Calories carried by the elf carrying the most
+pub fn part_one(calories: &[i32]) -> i32 {
+    top_three(calories)[2]
+}
+
+/// Calories carried by the top three elves
+pub fn part_two(calories: &[i32]) -> i32 {
+    top_three(calories).into_iter().sum()
+}
+
+/// Each elf's total as a single snack, which [`parse`] adds back up to the same totals
+pub fn to_text(calories: &[i32]) -> String {
+    calories
+        .iter()
+        .map(|total| format!("{total}\n"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `size` elves carrying a handful of snacks each
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..rng.range(1..=8))
+                .map(|_| format!("{}\n", rng.range(1000..=20000)))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part_two(input).into())
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
@@ -0,0 +1,217 @@
+//! # Day 2: Rock Paper Scissors
+use std::{fmt::Display, str::FromStr};
+
+use crate::gen::Rng;
+use crate::{Answer, ParseError, Result, Solution};
+
+const WIN: u64 = 6;
+const DRAW: u64 = 3;
+const LOSE: u64 = 0;
+
+const ROCK: u64 = 1;
+const PAPER: u64 = 2;
+const SCISSORS: u64 = 3;
+
+/// Error when parsing instruction value
+#[derive(Debug)]
+pub struct ParseInsErr(Option<char>);
+
+impl Display for ParseInsErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to match input to enum value: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseInsErr {}
+
+/// Left instruction value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsLeft {
+    A,
+    B,
+    C,
+}
+
+impl Display for InsLeft {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InsLeft::A => write!(f, "A"),
+            InsLeft::B => write!(f, "B"),
+            InsLeft::C => write!(f, "C"),
+        }
+    }
+}
+
+impl FromStr for InsLeft {
+    type Err = ParseInsErr;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if let Some(letter) = s.chars().next() {
+            match letter {
+                'A' => Ok(InsLeft::A),
+                'B' => Ok(InsLeft::B),
+                'C' => Ok(InsLeft::C),
+                _ => Err(ParseInsErr(Some(letter))),
+            }
+        } else {
+            Err(ParseInsErr(None))
+        }
+    }
+}
+
+/// Right instruction value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsRight {
+    X,
+    Y,
+    Z,
+}
+
+impl Display for InsRight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InsRight::X => write!(f, "X"),
+            InsRight::Y => write!(f, "Y"),
+            InsRight::Z => write!(f, "Z"),
+        }
+    }
+}
+
+impl FromStr for InsRight {
+    type Err = ParseInsErr;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if let Some(letter) = s.chars().next() {
+            match letter {
+                'X' => Ok(InsRight::X),
+                'Y' => Ok(InsRight::Y),
+                'Z' => Ok(InsRight::Z),
+                _ => Err(ParseInsErr(Some(letter))),
+            }
+        } else {
+            Err(ParseInsErr(None))
+        }
+    }
+}
+
+/// Instructions for one round in the strategy guide
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round(pub InsLeft, pub InsRight);
+
+/// The same `A X` as in the guide
+impl Display for Round {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.0, self.1)
+    }
+}
+
+impl TryFrom<&str> for Round {
+    type Error = ParseError;
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        let start = value.len() - value.trim_start().len();
+        if let Some(split) = value.trim().split_once(' ') {
+            let left: InsLeft = split.0.parse().map_err(|err: ParseInsErr| {
+                ParseError::at(err.to_string(), value, start, split.0.len())
+            })?;
+            let right_at = start + split.0.len() + 1;
+            let right: InsRight = split.1.parse().map_err(|err: ParseInsErr| {
+                ParseError::at(err.to_string(), value, right_at, split.1.len())
+            })?;
+            Ok(Round(left, right))
+        } else {
+            Err(ParseError::whole_line("Round is missing a space", value))
+        }
+    }
+}
+
+/// Interpret the strategy as (opponent move, my move) and assuming all went as planned, derive the score
+/// A | X = ROCK, B | Y = PAPER, C | Z = SCISSORS
+fn score_part1(round: Round) -> u64 {
+    match round {
+        Round(InsLeft::A, InsRight::X) => DRAW + ROCK,
+        Round(InsLeft::B, InsRight::X) => LOSE + ROCK,
+        Round(InsLeft::C, InsRight::X) => WIN + ROCK,
+        Round(InsLeft::A, InsRight::Y) => WIN + PAPER,
+        Round(InsLeft::B, InsRight::Y) => DRAW + PAPER,
+        Round(InsLeft::C, InsRight::Y) => LOSE + PAPER,
+        Round(InsLeft::A, InsRight::Z) => LOSE + SCISSORS,
+        Round(InsLeft::B, InsRight::Z) => WIN + SCISSORS,
+        Round(InsLeft::C, InsRight::Z) => DRAW + SCISSORS,
+    }
+}
+
+/// Interpret the strategy as (opponent move, desired outcome) and assuming all went as planned, derive the score
+/// A = ROCK, B = PAPER, C = SCISSORS
+/// X = LOSE, Y = DRAW, Z = WIN
+fn score_part2(round: Round) -> u64 {
+    match round {
+        Round(InsLeft::A, InsRight::X) => LOSE + SCISSORS,
+        Round(InsLeft::B, InsRight::X) => LOSE + ROCK,
+        Round(InsLeft::C, InsRight::X) => LOSE + PAPER,
+        Round(InsLeft::A, InsRight::Y) => DRAW + ROCK,
+        Round(InsLeft::B, InsRight::Y) => DRAW + PAPER,
+        Round(InsLeft::C, InsRight::Y) => DRAW + SCISSORS,
+        Round(InsLeft::A, InsRight::Z) => WIN + PAPER,
+        Round(InsLeft::B, InsRight::Z) => WIN + SCISSORS,
+        Round(InsLeft::C, InsRight::Z) => WIN + ROCK,
+    }
+}
+
+/// One round per line
+pub fn parse(input: &str) -> Result<Vec<Round>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| Round::try_from(line).map_err(|err| err.on_line(idx + 1).into()))
+        .collect()
+}
+
+/// Total score reading the guide as my moves
+pub fn part_one(rounds: &[Round]) -> u64 {
+    rounds.iter().copied().map(score_part1).sum()
+}
+
+/// Total score reading the guide as the outcomes to aim for
+pub fn part_two(rounds: &[Round]) -> u64 {
+    rounds.iter().copied().map(score_part2).sum()
+}
+
+/// One round per line, just like the guide
+pub fn to_text(rounds: &[Round]) -> String {
+    rounds.iter().map(|round| format!("{round}\n")).collect()
+}
+
+/// `size` rounds of the strategy guide
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let rounds = (0..size)
+        .map(|_| {
+            Round(
+                *rng.pick(&[InsLeft::A, InsLeft::B, InsLeft::C]),
+                *rng.pick(&[InsRight::X, InsRight::Y, InsRight::Z]),
+            )
+        })
+        .collect::<Vec<_>>();
+    to_text(&rounds)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Round>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part_two(input).into())
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
@@ -0,0 +1,156 @@
+//! # Day 3: Rucksack Reorganization
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::gen::Rng;
+use crate::{Answer, ParseError, Result, Solution};
+
+fn priority(c: char) -> Option<u8> {
+    match c {
+        'A'..='Z' => Some((c as u8) - 38),
+        'a'..='z' => Some((c as u8) - 96),
+        _ => None,
+    }
+}
+
+/// Sum of the priorities of the item found in both compartments of each rucksack
+pub fn part_one(input: &[Vec<u8>]) -> u32 {
+    input
+        .iter()
+        .flat_map(|bag| {
+            let (left, right) = bag.split_at(bag.len() / 2);
+            let (mut left_set, mut right_set) = (
+                HashSet::with_capacity(bag.len() / 2), // Rough guess for capacity
+                HashSet::with_capacity(bag.len() / 2),
+            );
+            left.iter().zip(right.iter()).find_map(|(&l, &r)| {
+                if l == r {
+                    return Some(l as u32);
+                };
+                if left_set.contains(&r) {
+                    return Some(r as u32);
+                }
+                if right_set.contains(&l) {
+                    return Some(l as u32);
+                }
+                left_set.insert(l);
+                right_set.insert(r);
+                None
+            })
+        })
+        .sum()
+}
+
+/// Sum of the priorities of the badge carried by each group of three
+pub fn part_two(input: &[Vec<u8>]) -> u32 {
+    let mut lines = input.iter();
+    std::iter::from_fn(|| Some([lines.next()?, lines.next()?, lines.next()?]))
+        .flat_map(|bags| {
+            let mut history = HashMap::<u8, [bool; 3]>::new();
+            bags.into_iter().enumerate().find_map(|(idx, bag)| {
+                bag.iter().find_map(|&item| {
+                    let record = history.entry(item).or_default();
+                    record[idx] = true;
+                    record.iter().all(|r| *r).then_some(item as u32)
+                })
+            })
+        })
+        .sum()
+}
+
+/// One rucksack per line, with each item turned into its priority
+pub fn parse(input: &str) -> Result<Vec<Vec<u8>>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            if line.len() % 2 != 0 {
+                return Err(ParseError::whole_line(
+                    "Rucksack compartments must be the same size",
+                    line,
+                )
+                .on_line(idx + 1)
+                .into());
+            }
+            line.char_indices()
+                .map(|(col, c)| {
+                    priority(c).ok_or_else(|| {
+                        ParseError::at("Invalid item", line, col, c.len_utf8())
+                            .on_line(idx + 1)
+                            .into()
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Every type of item, in order of priority
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// One rucksack per line with the priorities turned back into items
+///
+/// # Panics
+///
+/// If a priority isn't from 1 to 52.
+pub fn to_text(rucksacks: &[Vec<u8>]) -> String {
+    let mut text = String::new();
+    for rucksack in rucksacks {
+        text.extend(
+            rucksack
+                .iter()
+                .map(|&priority| ITEMS.as_bytes()[usize::from(priority) - 1] as char),
+        );
+        text.push('\n');
+    }
+    text
+}
+
+/// `size` rucksacks rounded up to whole groups of three, each with exactly one item in both
+/// compartments and each group with exactly one badge
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..(size + 2) / 3 {
+        let mut items = ITEMS.chars().collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        let (&badge, others) = items.split_last().expect("There are 52 items");
+        // Each rucksack in the group draws from its own third of the other items, so the badge is
+        // the only item they all have
+        for pool in others.chunks(others.len() / 3) {
+            let (shared, pool) = (pool[0], &pool[1..]);
+            let (left_only, right_only) = pool.split_at(pool.len() / 2);
+            let len = rng.range(2..=16);
+            let mut left = vec![badge, shared];
+            left.extend((2..len).map(|_| *rng.pick(left_only)));
+            let mut right = vec![shared];
+            right.extend((1..len).map(|_| *rng.pick(right_only)));
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+            input.extend(left.into_iter().chain(right));
+            input.push('\n');
+        }
+    }
+    input
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<u8>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part_two(input).into())
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
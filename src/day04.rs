@@ -0,0 +1,98 @@
+//! # Day 4: Camp Cleanup
+use std::ops::RangeInclusive;
+
+use crate::gen::Rng;
+use crate::parse::{self, Line};
+use crate::{Answer, ParseError, Result, Solution};
+
+/// The section assignments for a pair of elves
+pub type Pair = (RangeInclusive<u32>, RangeInclusive<u32>);
+
+/// One pair per line
+pub fn parse(input: &str) -> Result<Vec<Pair>> {
+    Ok(parse::lines(input)
+        .map(parse_pair)
+        .collect::<std::result::Result<_, _>>()?)
+}
+
+/// Pairs where one elf's sections cover all of the other's
+pub fn part_one(pairs: &[Pair]) -> usize {
+    pairs
+        .iter()
+        .filter(|(one, two)| either_range_contains_the_other(one, two))
+        .count()
+}
+
+/// Pairs with any overlap at all
+pub fn part_two(pairs: &[Pair]) -> usize {
+    pairs
+        .iter()
+        .filter(|(one, two)| ranges_overlap_at_all(one, two))
+        .count()
+}
+
+/// One `a-b,c-d` line per pair
+pub fn to_text(pairs: &[Pair]) -> String {
+    pairs
+        .iter()
+        .map(|(one, two)| {
+            format!(
+                "{}-{},{}-{}\n",
+                one.start(),
+                one.end(),
+                two.start(),
+                two.end()
+            )
+        })
+        .collect()
+}
+
+/// `size` pairs of elves, each assigned some of sections 1 to 99
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut sections = || {
+        let start = rng.range(1..=99) as u32;
+        start..=rng.range(start as usize..=99) as u32
+    };
+    let pairs = (0..size)
+        .map(|_| (sections(), sections()))
+        .collect::<Vec<_>>();
+    to_text(&pairs)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Pair>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part_two(input).into())
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+/// Parse `a-b,c-d` into two ranges
+fn parse_pair(line: Line) -> std::result::Result<Pair, ParseError> {
+    let [one, two] = line.whole().split(",")?;
+    let [one_begin, one_end] = one.integers("-")?;
+    let [two_begin, two_end] = two.integers("-")?;
+    Ok((one_begin..=one_end, two_begin..=two_end))
+}
+
+fn either_range_contains_the_other(a: &RangeInclusive<u32>, b: &RangeInclusive<u32>) -> bool {
+    (a.start() <= b.start() && a.end() >= b.end()) || (b.start() <= a.start() && b.end() >= a.end())
+}
+
+fn ranges_overlap_at_all(a: &RangeInclusive<u32>, b: &RangeInclusive<u32>) -> bool {
+    b.contains(a.start()) || b.contains(a.end()) || a.contains(b.end()) || a.contains(b.end())
+}
@@ -0,0 +1,212 @@
+//! # Day 5: Supply Stacks
+//!
+//! A drawing of the stacks, a blank line, then the rearrangement procedure
+use crate::gen::{self, Rng};
+use crate::parse::{self, Line, Piece};
+use crate::{Answer, Error, ParseError, Result, Solution};
+
+/// Crate labels from the bottom of each stack up
+pub type Stacks = Vec<Vec<char>>;
+
+/// (count, source, target) with the stacks counted from 0
+pub type Command = (usize, usize, usize);
+
+/// Each stack in the drawing is 3 characters wide with a space between
+const COLUMN_WIDTH: usize = 4;
+
+/// The stack drawing followed by the procedure
+pub fn parse_input(input: &str) -> std::result::Result<(Stacks, Vec<Command>), ParseError> {
+    let mut groups = parse::groups(input);
+    let drawing = groups.next().unwrap_or_default();
+    let procedure = groups.next().unwrap_or_default();
+    if let Some(extra) = groups.next().and_then(|group| group.first().copied()) {
+        return Err(extra.whole().error("Expected nothing after the procedure"));
+    }
+
+    // The bottom line of the drawing just numbers the stacks
+    let (numbers, rows) = match drawing.split_last() {
+        Some((numbers, rows)) => (Some(numbers), rows),
+        None => (None, &[][..]),
+    };
+    let mut stacks = parse::columns(rows, COLUMN_WIDTH)
+        .into_iter()
+        .map(|column| {
+            column
+                .into_iter()
+                .rev()
+                .filter_map(|cell| parse_crate(cell).transpose())
+                .collect::<std::result::Result<Vec<_>, _>>()
+        })
+        .collect::<std::result::Result<Stacks, _>>()?;
+    // Counting the numbers rather than columns, as two digit numbers push the columns along
+    let stack_count = numbers.map_or(0, |numbers| numbers.text.split_whitespace().count());
+    if stacks.len() < stack_count {
+        stacks.resize(stack_count, Vec::new());
+    }
+
+    let commands = procedure
+        .into_iter()
+        .map(|line| parse_command(line, stacks.len()))
+        .collect::<std::result::Result<_, _>>()?;
+    Ok((stacks, commands))
+}
+
+/// A crate is drawn as `[A]`, an empty cell means the stack isn't that tall
+fn parse_crate(cell: Piece) -> std::result::Result<Option<char>, ParseError> {
+    let cell = cell.trim();
+    if cell.text.is_empty() {
+        return Ok(None);
+    }
+    let mut chars = cell.text.chars();
+    match (chars.next(), chars.next(), chars.next(), chars.next()) {
+        (Some('['), Some(label), Some(']'), None) if label.is_ascii_alphabetic() => Ok(Some(label)),
+        _ => Err(cell.error("Expected a crate like [A]")),
+    }
+}
+
+/// Parse `move N from X to Y` into (N, X, Y) with the stacks counted from 0
+fn parse_command(line: Line, stack_count: usize) -> std::result::Result<Command, ParseError> {
+    let [count, source, target] = line.template("move {} from {} to {}")?;
+    let stack = |number: Piece| match number.integer::<usize>()? {
+        n @ 1.. if n <= stack_count => Ok(n - 1),
+        _ => Err(number.error(format!("No such stack, there are {stack_count}"))),
+    };
+    Ok((count.integer()?, stack(source)?, stack(target)?))
+}
+
+/// Run the crane over the stacks and read off the top crates, the newer model moves several crates at once
+pub fn operate_crane(
+    stacks: &[Vec<char>],
+    commands: &[Command],
+    moves_many: bool,
+) -> Result<String> {
+    let mut stacks = stacks.to_vec();
+    for &(count, source, target) in commands {
+        let source_len = stacks[source].len();
+        if count > source_len {
+            return Err(Error::NoAnswer(format!(
+                "Can't move {count} crates from stack {} which only has {source_len}",
+                source + 1
+            )));
+        }
+        let mut to_move = stacks[source]
+            .drain(source_len - count..)
+            .collect::<Vec<_>>();
+        if !moves_many {
+            to_move.reverse();
+        }
+        crate::verbose!(
+            "Move {} from {} to {}",
+            to_move.iter().collect::<String>(),
+            source + 1,
+            target + 1
+        );
+        stacks[target].append(&mut to_move);
+        crate::trace!("Stacks are now {stacks:?}");
+    }
+    let mut result = String::new();
+    for stack in stacks {
+        if let Some(label) = stack.last() {
+            result.push(*label);
+        }
+    }
+    Ok(result)
+}
+
+/// Top crates after the CrateMover 9000 moves them one at a time
+pub fn part_one((stacks, commands): &(Stacks, Vec<Command>)) -> Result<String> {
+    operate_crane(stacks, commands, false)
+}
+
+/// Top crates after the CrateMover 9001 moves them several at a time
+pub fn part_two((stacks, commands): &(Stacks, Vec<Command>)) -> Result<String> {
+    operate_crane(stacks, commands, true)
+}
+
+/// Two to nine stacks of crates and `size` moves, none of which take more crates than there are
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut stacks = (0..rng.range(2..=9))
+        .map(|_| {
+            (0..rng.range(0..=8))
+                .map(|_| rng.char(gen::UPPERCASE))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Stacks>();
+    if stacks.iter().all(Vec::is_empty) {
+        stacks[0].push(rng.char(gen::UPPERCASE));
+    }
+    let mut procedure = (stacks.clone(), Vec::with_capacity(size));
+
+    for _ in 0..size {
+        let sources = (0..stacks.len())
+            .filter(|&stack| !stacks[stack].is_empty())
+            .collect::<Vec<_>>();
+        let source = *rng.pick(&sources);
+        let count = rng.range(1..=stacks[source].len());
+        let target = (source + rng.range(1..=stacks.len() - 1)) % stacks.len();
+        let left = stacks[source].len() - count;
+        let mut moved = stacks[source].split_off(left);
+        stacks[target].append(&mut moved);
+        procedure.1.push((count, source, target));
+    }
+    to_text(&procedure)
+}
+
+/// The drawing, a blank line and then the procedure, which [`parse_input`] reads back as they were
+pub fn to_text((stacks, commands): &(Stacks, Vec<Command>)) -> String {
+    let mut text = draw(stacks);
+    text.push('\n');
+    for (count, source, target) in commands {
+        text.push_str(&format!(
+            "move {count} from {} to {}\n",
+            source + 1,
+            target + 1
+        ));
+    }
+    text
+}
+
+/// The drawing of the stacks, tallest first, with the numbers underneath
+fn draw(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or_default();
+    let mut drawing = String::new();
+    for level in (0..height).rev() {
+        let cells = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(label) => format!("[{label}]"),
+                None => "   ".to_string(),
+            })
+            .collect::<Vec<_>>();
+        drawing.push_str(&cells.join(" "));
+        drawing.push('\n');
+    }
+    let numbers = (1..=stacks.len())
+        .map(|number| format!(" {number} "))
+        .collect::<Vec<_>>();
+    drawing.push_str(&numbers.join(" "));
+    drawing.push('\n');
+    drawing
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Stacks, Vec<Command>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
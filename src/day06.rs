@@ -0,0 +1,126 @@
+//! # Day 6: Tuning Trouble
+use crate::gen::{self, Rng};
+use crate::solution::Implementation;
+use crate::{Answer, Error, Part, Result, Solution};
+
+// Naive implementation of all_unique
+fn all_unique(bytes: &[u8]) -> bool {
+    if bytes.len() < 2 {
+        return true;
+    }
+    let (head, tail) = bytes.split_at(1);
+    !tail.contains(&head[0]) && all_unique(tail)
+}
+
+/// Number of characters processed by the end of the first window of unique characters
+pub fn find_marker(signal: &str, window_len: usize) -> Option<usize> {
+    // Where each byte was last seen, so the window can skip straight past a repeat
+    let mut last_seen = [None; 256];
+    let mut start = 0;
+    for (idx, &byte) in signal.as_bytes().iter().enumerate() {
+        if let Some(previous) = last_seen[byte as usize] {
+            start = start.max(previous + 1);
+        }
+        last_seen[byte as usize] = Some(idx);
+        if idx + 1 - start >= window_len {
+            return Some(idx + 1);
+        }
+    }
+    None
+}
+
+/// [`find_marker`] checking every window from scratch
+pub fn find_marker_naive(signal: &str, window_len: usize) -> Option<usize> {
+    signal
+        .as_bytes()
+        .windows(window_len)
+        .enumerate()
+        .find(|(_, w)| all_unique(w))
+        .map(|f| f.0 + window_len)
+}
+
+type Finder = fn(&str, usize) -> Option<usize>;
+
+fn marker_or_no_answer(find: Finder, signal: &str, window_len: usize) -> Result<usize> {
+    find(signal, window_len)
+        .ok_or_else(|| Error::NoAnswer(format!("No run of {window_len} unique characters")))
+}
+
+/// Just one line today
+pub fn parse(input: &str) -> String {
+    input.lines().next().unwrap_or_default().to_string()
+}
+
+/// End of the start-of-packet marker
+pub fn part_one(signal: &str) -> Result<usize> {
+    marker_or_no_answer(find_marker, signal, 4)
+}
+
+/// End of the start-of-message marker
+pub fn part_two(signal: &str) -> Result<usize> {
+    marker_or_no_answer(find_marker, signal, 14)
+}
+
+/// The signal on a line of its own
+pub fn to_text(signal: &str) -> String {
+    format!("{signal}\n")
+}
+
+/// A stream of `size` letters, or 14 if that's more, ending in 14 different letters so both
+/// markers are always found
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut signal = (14..size)
+        .map(|_| rng.char(gen::LOWERCASE))
+        .collect::<String>();
+    let mut letters = gen::LOWERCASE.chars().collect::<Vec<_>>();
+    rng.shuffle(&mut letters);
+    signal.extend(&letters[..14]);
+    signal + "\n"
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse(input))
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn implementations(&self) -> Vec<Implementation<Self::Input>> {
+        vec![
+            Implementation {
+                name: "naive",
+                part: Part::One,
+                solve: |signal| Ok(marker_or_no_answer(find_marker_naive, signal, 4)?.into()),
+            },
+            Implementation {
+                name: "fast",
+                part: Part::One,
+                solve: |signal| Ok(marker_or_no_answer(find_marker, signal, 4)?.into()),
+            },
+            Implementation {
+                name: "naive",
+                part: Part::Two,
+                solve: |signal| Ok(marker_or_no_answer(find_marker_naive, signal, 14)?.into()),
+            },
+            Implementation {
+                name: "fast",
+                part: Part::Two,
+                solve: |signal| Ok(marker_or_no_answer(find_marker, signal, 14)?.into()),
+            },
+        ]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
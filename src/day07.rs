@@ -0,0 +1,519 @@
+//! # Day 7: No Space Left On Device
+//!
+//! In this one I decided to use some external crates that I've been curious about. The crates are:
+//! slotmap for acting like a virtual memory system of sorts, and smallvec/smallstr for the sake of
+//! keeping more data on the stack and reducing indirection when I expect to have many small strings
+//! and lists.
+//!
+//! I may come back and clean this code up a bit later if I have the time, but, this is enough to
+//! demonstrate the concept of circular references and other such things that are hard to do in
+//! safe rust "natively" but not too hard to do with a data oriented approach.
+//!
+use crate::gen::{self, Rng};
+use crate::{Answer, Error, ParseError, Result, Solution};
+
+pub mod fs {
+    use std::path::Path;
+
+    use slotmap::{DefaultKey, SlotMap};
+    use smallstr::SmallString;
+    use smallvec::SmallVec;
+
+    pub type Result<T> = std::result::Result<T, Error>;
+
+    #[allow(clippy::enum_variant_names)]
+    #[derive(Debug)]
+    pub enum Error {
+        NotFound,
+        NotFoundDest,
+        NotADirectory,
+    }
+
+    impl std::fmt::Display for Error {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Self::NotFound => write!(f, "NotFound"),
+                Self::NotFoundDest => write!(f, "NotFoundDest"),
+                Self::NotADirectory => write!(f, "NotADirectory"),
+            }
+        }
+    }
+
+    impl std::error::Error for Error {}
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Handle(DefaultKey);
+
+    #[derive(Debug)]
+    pub struct INode {
+        parent_handle: Option<Handle>,
+        handle: Handle,
+        name: SmallString<[u8; 14]>,
+        size: u64,
+        children: Option<SmallVec<[Handle; 10]>>,
+    }
+
+    impl INode {
+        pub fn is_dir(&self) -> bool {
+            self.children.is_some()
+        }
+
+        pub fn size(&self) -> u64 {
+            self.size
+        }
+
+        pub fn name(&self) -> &str {
+            &self.name
+        }
+    }
+
+    impl std::fmt::Display for INode {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            if self.children.is_none() {
+                write!(f, "{} {}", self.size, self.name)
+            } else {
+                write!(f, "dir {}", self.name)
+            }
+        }
+    }
+
+    pub struct Fs {
+        data: SlotMap<DefaultKey, INode>,
+        root: Handle,
+    }
+
+    /// The same tree of names and sizes, with every directory's entries in the same order
+    impl PartialEq for Fs {
+        fn eq(&self, other: &Self) -> bool {
+            self.same_tree(self.root, other, other.root)
+        }
+    }
+
+    impl Default for Fs {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl Fs {
+        pub fn new() -> Self {
+            let mut fs = Self {
+                data: SlotMap::with_key(),
+                root: Handle(DefaultKey::default()),
+            };
+            fs.root = fs.create_dir("/").unwrap();
+            fs
+        }
+
+        pub fn root(&self) -> Handle {
+            self.root
+        }
+
+        fn same_tree(&self, handle: Handle, other: &Fs, other_handle: Handle) -> bool {
+            let (Some(inode), Some(other_inode)) = (self.get(handle), other.get(other_handle)) else {
+                return false;
+            };
+            inode.name == other_inode.name
+                && inode.size == other_inode.size
+                && match (&inode.children, &other_inode.children) {
+                    (Some(children), Some(other_children)) => {
+                        children.len() == other_children.len()
+                            && children
+                                .iter()
+                                .zip(other_children)
+                                .all(|(&child, &other_child)| {
+                                    self.same_tree(child, other, other_child)
+                                })
+                    }
+                    (None, None) => true,
+                    _ => false,
+                }
+        }
+
+        pub fn create_file(&mut self, name: &str, size: u64) -> Result<Handle> {
+            Ok(Handle(self.data.insert_with_key(|key| INode {
+                parent_handle: None,
+                handle: Handle(key),
+                name: name.into(),
+                size,
+                children: None,
+            })))
+        }
+
+        pub fn create_dir(&mut self, name: &str) -> Result<Handle> {
+            Ok(Handle(self.data.insert_with_key(|key| INode {
+                parent_handle: None,
+                handle: Handle(key),
+                name: name.into(),
+                size: 0,
+                children: Some(SmallVec::new()),
+            })))
+        }
+
+        pub fn move_to(&mut self, handle: Handle, parent: Handle) -> Result<()> {
+            // Get any old parent ahead of time
+            let old_parent = {
+                self.data
+                    .get_mut(handle.0)
+                    .ok_or(Error::NotFound)?
+                    .parent_handle
+            };
+
+            // Establish the new parent handle is real and get it
+            let new_parent = {
+                let parent = self.data.get_mut(parent.0).ok_or(Error::NotFoundDest)?;
+                if !parent.is_dir() {
+                    return Err(Error::NotADirectory);
+                }
+                parent.handle
+            };
+
+            // Update the parent handle on the inode being moved
+            {
+                let node = self.data.get_mut(handle.0).ok_or(Error::NotFound)?;
+                node.parent_handle = Some(new_parent);
+            }
+
+            // Remove the inode from the old parent's children
+            if let Some(old_parent) = old_parent {
+                let old_parent_node = self.data.get_mut(old_parent.0);
+                if let Some(old_parent_node) = old_parent_node {
+                    if let Some(children) = old_parent_node.children.as_mut() {
+                        children.retain(|h| h.0 != handle.0);
+                    }
+                }
+            }
+
+            // Add the inode to the new parent's children
+            let children = self
+                .data
+                .get_mut(new_parent.0)
+                .ok_or(Error::NotFoundDest)?
+                .children
+                .as_mut()
+                .expect("By all accounts this was a directory, and yet...");
+
+            // Obviously could be more efficient with a map
+            if !children.contains(&handle) {
+                children.push(handle);
+            }
+
+            Ok(())
+        }
+
+        pub fn get(&self, handle: Handle) -> Option<&INode> {
+            self.data.get(handle.0)
+        }
+
+        pub fn get_child(&self, handle: Handle, child: &str) -> Result<Handle> {
+            let inode = self.data.get(handle.0).ok_or(Error::NotFound)?;
+            if !inode.is_dir() {
+                return Err(Error::NotADirectory);
+            }
+            if let Some(children) = inode.children.as_ref() {
+                for child_handle in children {
+                    let child_node = self.data.get(child_handle.0).ok_or(Error::NotFound)?;
+                    if child_node.name == child {
+                        return Ok(child_node.handle);
+                    }
+                }
+            }
+            Err(Error::NotFound)
+        }
+
+        pub fn cd<P: AsRef<Path>>(&self, cwd: Handle, path: P) -> Result<Handle> {
+            let mut handle = cwd;
+            for component in path.as_ref().components() {
+                match component {
+                    std::path::Component::RootDir => {
+                        handle = self.root;
+                    }
+                    std::path::Component::Normal(name) => {
+                        let name = name.to_str().ok_or(Error::NotFound)?;
+                        let inode = self.data.get(handle.0).ok_or(Error::NotFound)?;
+                        if let Some(children) = inode.children.as_ref() {
+                            for child in children {
+                                let child = self.data.get(child.0).ok_or(Error::NotFound)?;
+                                if child.name == name {
+                                    handle = child.handle;
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                    std::path::Component::ParentDir => {
+                        let inode = self.data.get(handle.0).ok_or(Error::NotFound)?;
+                        if let Some(parent) = inode.parent_handle {
+                            handle = parent;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Ok(handle)
+        }
+
+        pub fn ls(&self, handle: Handle) -> Result<SmallVec<[Handle; 10]>> {
+            let inode = self.data.get(handle.0).ok_or(Error::NotFound)?;
+            if let Some(children) = inode.children.as_ref() {
+                Ok(children.clone())
+            } else {
+                Err(Error::NotADirectory)
+            }
+        }
+    }
+}
+
+const DIR_PREFIX: &str = "dir ";
+const CD_PREFIX: &str = "$ cd ";
+const LS_COMMAND: &str = "$ ls";
+const TOTAL_SPACE: u64 = 70000000;
+const MIN_FREE_SPACE: u64 = 30000000;
+
+/// Total size of everything under a handle, recording the size of every directory in the cache
+pub fn walk_n_count(
+    fs: &fs::Fs,
+    handle: fs::Handle,
+    cache: &mut std::collections::HashMap<fs::Handle, u64>,
+) -> u64 {
+    let inode = fs.get(handle).unwrap();
+    if let Ok(children) = fs.ls(handle) {
+        let mut count = 0;
+        for child in children {
+            count += walk_n_count(fs, child, cache);
+        }
+        cache.insert(handle, count);
+        count
+    } else {
+        inode.size()
+    }
+}
+
+/// Apply one line of the terminal session to the file system and return the new working directory
+fn replay_line(
+    fs: &mut fs::Fs,
+    cwd: fs::Handle,
+    line: &str,
+) -> std::result::Result<fs::Handle, ParseError> {
+    let fs_err = |err: fs::Error| ParseError::whole_line(format!("File system error {err}"), line);
+    if let Some(path) = line.strip_prefix(CD_PREFIX) {
+        crate::verbose!("cd {path}");
+        if path == "/" {
+            Ok(fs.root())
+        } else if path == ".." {
+            fs.cd(cwd, "..").map_err(fs_err)
+        } else {
+            match fs.get_child(cwd, path) {
+                Ok(new_cwd) => Ok(new_cwd),
+                Err(fs::Error::NotFound) => {
+                    let new_dir = fs.create_dir(path).map_err(fs_err)?;
+                    fs.move_to(new_dir, cwd).map_err(fs_err)?;
+                    Ok(new_dir)
+                }
+                Err(err) => Err(fs_err(err)),
+            }
+        }
+    } else if let Some(path) = line.strip_prefix(DIR_PREFIX) {
+        let new_dir = fs.create_dir(path).map_err(fs_err)?;
+        fs.move_to(new_dir, cwd).map_err(fs_err)?;
+        Ok(cwd)
+    } else if line == LS_COMMAND {
+        Ok(cwd)
+    } else if line
+        .chars()
+        .next()
+        .map(|c| c.is_ascii_digit())
+        .unwrap_or(false)
+    {
+        let (size, name) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::whole_line("Expected a file size and name", line))?;
+        // The size starts the line
+        let size = size.parse().map_err(|err| {
+            ParseError::at(format!("Invalid file size ({err})"), line, 0, size.len())
+        })?;
+        let new_file = fs.create_file(name, size).map_err(fs_err)?;
+        fs.move_to(new_file, cwd).map_err(fs_err)?;
+        Ok(cwd)
+    } else {
+        Err(ParseError::whole_line("Unrecognised terminal output", line))
+    }
+}
+
+/// Replay the terminal session to rebuild the file system
+pub fn parse(input: &str) -> Result<fs::Fs> {
+    let mut fs = fs::Fs::new();
+    let mut cwd = fs.root();
+    for (idx, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        cwd = replay_line(&mut fs, cwd, line).map_err(|err| err.on_line(idx + 1))?;
+    }
+    Ok(fs)
+}
+
+/// Sum of every directory no bigger than 100000
+pub fn part_one(fs: &fs::Fs) -> u64 {
+    let mut cache = std::collections::HashMap::new();
+    walk_n_count(fs, fs.root(), &mut cache);
+
+    cache
+        .values()
+        .filter_map(|&size| if size <= 100000 { Some(size) } else { None })
+        .sum()
+}
+
+/// Size of the smallest directory that frees up enough space when deleted
+pub fn part_two(fs: &fs::Fs) -> Result<u64> {
+    let mut cache = std::collections::HashMap::new();
+    walk_n_count(fs, fs.root(), &mut cache);
+
+    let used_space = cache[&fs.root()];
+    let free_space = TOTAL_SPACE
+        .checked_sub(used_space)
+        .ok_or_else(|| Error::NoAnswer(format!("{used_space} used is more than the whole disk")))?;
+    let needed_space = MIN_FREE_SPACE.checked_sub(free_space).ok_or_else(|| {
+        Error::NoAnswer(format!("{free_space} is already free, nothing to delete"))
+    })?;
+    let answer: u64 = cache
+        .values()
+        .filter_map(|&size| {
+            if size >= needed_space {
+                Some(size)
+            } else {
+                None
+            }
+        })
+        .min()
+        .ok_or_else(|| Error::NoAnswer("No directory is big enough".to_string()))?;
+    Ok(answer)
+}
+
+/// An entry in a generated directory, subdirectories are numbered
+#[derive(Clone)]
+enum Entry {
+    Dir(String, usize),
+    File(String, u64),
+}
+
+/// A session exploring `size` files, at least one, spread over about a fifth as many directories
+///
+/// Between 40000001 and 70000000 is used so there's never enough space free already, which
+/// means deleting the root is always an answer to part two.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let files = size.max(1);
+    let total = rng.range(40_000_001..=70_000_000) as u64;
+    let weights = (0..files)
+        .map(|_| rng.range(1..=1000) as u64)
+        .collect::<Vec<_>>();
+    let weight = weights.iter().sum::<u64>();
+    let mut sizes = weights
+        .iter()
+        .map(|share| total * share / weight)
+        .collect::<Vec<_>>();
+    sizes[0] += total - sizes.iter().sum::<u64>();
+
+    // Directory 0 is the root, every other one goes in a directory made before it
+    let mut dirs = vec![Vec::new(); 1 + files / 5];
+    for dir in 1..dirs.len() {
+        let parent = rng.below(dir);
+        let name = entry_name(rng, &dirs[parent], false);
+        dirs[parent].push(Entry::Dir(name, dir));
+    }
+    for size in sizes {
+        let dir = rng.below(dirs.len());
+        let name = entry_name(rng, &dirs[dir], true);
+        dirs[dir].push(Entry::File(name, size));
+    }
+    for entries in &mut dirs {
+        rng.shuffle(entries);
+    }
+
+    // Parents always come before their subdirectories, so their handles are known by then
+    let mut fs = fs::Fs::new();
+    let mut handles = vec![fs.root(); dirs.len()];
+    for (dir, entries) in dirs.iter().enumerate() {
+        for entry in entries {
+            let handle = match entry {
+                Entry::Dir(name, subdir) => {
+                    let handle = fs.create_dir(name).expect("Creating never fails");
+                    handles[*subdir] = handle;
+                    handle
+                }
+                Entry::File(name, size) => {
+                    fs.create_file(name, *size).expect("Creating never fails")
+                }
+            };
+            fs.move_to(handle, handles[dir])
+                .expect("Every directory is made before anything goes in it");
+        }
+    }
+    to_text(&fs)
+}
+
+/// A name that isn't already in the directory, files sometimes have an extension
+fn entry_name(rng: &mut Rng, entries: &[Entry], file: bool) -> String {
+    loop {
+        let mut name = (0..rng.range(1..=8))
+            .map(|_| rng.char(gen::LOWERCASE))
+            .collect::<String>();
+        if file && rng.one_in(2) {
+            name.push('.');
+            name.extend((0..rng.range(1..=3)).map(|_| rng.char(gen::LOWERCASE)));
+        }
+        let taken = entries.iter().any(|entry| match entry {
+            Entry::Dir(other, _) | Entry::File(other, _) => *other == name,
+        });
+        if !taken {
+            return name;
+        }
+    }
+}
+
+/// A terminal session that lists every directory and visits each subdirectory in turn, which
+/// [`parse`] replays into the same file system
+pub fn to_text(fs: &fs::Fs) -> String {
+    let mut session = format!("{CD_PREFIX}/\n");
+    transcript(fs, fs.root(), &mut session);
+    session
+}
+
+fn transcript(fs: &fs::Fs, dir: fs::Handle, session: &mut String) {
+    session.push_str(LS_COMMAND);
+    session.push('\n');
+    let children = fs.ls(dir).unwrap_or_default();
+    let children = children
+        .iter()
+        .filter_map(|&child| fs.get(child).map(|inode| (child, inode)));
+    for (_, inode) in children.clone() {
+        session.push_str(&format!("{inode}\n"));
+    }
+    for (child, inode) in children.filter(|(_, inode)| inode.is_dir()) {
+        session.push_str(&format!("{CD_PREFIX}{}\n", inode.name()));
+        transcript(fs, child, session);
+        session.push_str(&format!("{CD_PREFIX}..\n"));
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = fs::Fs;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, fs: &Self::Input) -> Result<Answer> {
+        Ok(part_one(fs).into())
+    }
+
+    fn part_two(&self, fs: &Self::Input) -> Result<Answer> {
+        Ok(part_two(fs)?.into())
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
@@ -0,0 +1,193 @@
+//! # Day 8: Treetop Tree House
+use crate::gen::Rng;
+use crate::grid::{Direction, Grid, Point};
+use crate::solution::Implementation;
+use crate::{Answer, Part, Result, Solution};
+
+/// Tree heights from 0 to 9
+pub type Forest = Grid<u8>;
+
+/// A tree is visible if every tree between it and some edge is shorter, so the edges always are
+pub fn visible_from_outside(forest: &Forest) -> u64 {
+    forest
+        .iter()
+        .filter(|&(point, &height)| {
+            Direction::ORTHOGONAL.into_iter().any(|direction| {
+                forest
+                    .ray(point, direction)
+                    .all(|(_, &other)| other < height)
+            })
+        })
+        .count() as u64
+}
+
+/// [`visible_from_outside`] in one sweep along each row and column from both ends, keeping track
+/// of the tallest tree so far
+pub fn visible_from_outside_sweep(forest: &Forest) -> u64 {
+    let mut visible = Grid::filled(forest.width(), forest.height(), false);
+    for line in sightlines(forest) {
+        let mut tallest = None;
+        for point in line {
+            let height = forest[point];
+            if tallest.map_or(true, |tallest| height > tallest) {
+                visible[point] = true;
+                tallest = Some(height);
+            }
+        }
+    }
+    visible.iter().filter(|(_, &visible)| visible).count() as u64
+}
+
+/// Every row and column from both ends, as the points in the order they're looked along
+fn sightlines(forest: &Forest) -> impl Iterator<Item = Vec<Point>> {
+    let (width, height) = (forest.width(), forest.height());
+    let rows = (0..height).map(move |y| (0..width).map(|x| (x, y)).collect::<Vec<_>>());
+    let columns = (0..width).map(move |x| (0..height).map(|y| (x, y)).collect::<Vec<_>>());
+    rows.chain(columns).flat_map(|line| {
+        let reversed = line.iter().rev().copied().collect();
+        [line, reversed]
+    })
+}
+
+/// Trees that can be seen from `point` looking in `direction`, up to and including the first one
+/// that's at least as tall
+fn viewing_distance(forest: &Forest, point: Point, direction: Direction) -> u64 {
+    let height = forest[point];
+    let mut distance = 0;
+    for (_, &other) in forest.ray(point, direction) {
+        distance += 1;
+        if other >= height {
+            break;
+        }
+    }
+    distance
+}
+
+/// A tree's scenic score multiplies its viewing distance in each direction
+pub fn highest_scenic_score(forest: &Forest) -> u64 {
+    forest
+        .points()
+        .map(|point| {
+            let score = Direction::ORTHOGONAL
+                .into_iter()
+                .map(|direction| viewing_distance(forest, point, direction))
+                .product::<u64>();
+            crate::trace!(
+                "Tree at {point:?} of height {} scores {score}",
+                forest[point]
+            );
+            score
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+/// [`highest_scenic_score`] in one sweep along each row and column from both ends, remembering
+/// where the last tree of each height was so each tree can see straight back to whatever blocks it
+pub fn highest_scenic_score_sweep(forest: &Forest) -> u64 {
+    let mut scores = Grid::filled(forest.width(), forest.height(), 1u64);
+    for line in sightlines(forest) {
+        // The edge counts as position 0, it stops the view just like a tall tree would
+        let mut last_seen = [0; 10];
+        for (idx, &point) in line.iter().enumerate() {
+            let height = usize::from(forest[point]);
+            let blocker = last_seen[height..]
+                .iter()
+                .copied()
+                .max()
+                .unwrap_or_default();
+            scores[point] *= (idx - blocker) as u64;
+            last_seen[height] = idx;
+        }
+    }
+    scores
+        .iter()
+        .map(|(point, &score)| {
+            crate::trace!(
+                "Tree at {point:?} of height {} scores {score}",
+                forest[point]
+            );
+            score
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+/// One row of tree heights per line
+pub fn parse(input: &str) -> Result<Forest> {
+    let forest = Grid::parse(input, |c| {
+        c.to_digit(10)
+            .map(|height| height as u8)
+            .ok_or("Tree height must be a digit")
+    })?;
+    crate::verbose!("{forest}");
+    Ok(forest)
+}
+
+/// Trees that can be seen from outside the grid
+pub fn part_one(forest: &Forest) -> u64 {
+    visible_from_outside_sweep(forest)
+}
+
+/// Best scenic score of any tree
+pub fn part_two(forest: &Forest) -> u64 {
+    highest_scenic_score_sweep(forest)
+}
+
+/// One row of heights per line, the same as the forest's [`Display`](std::fmt::Display)
+pub fn to_text(forest: &Forest) -> String {
+    forest.to_string()
+}
+
+/// A square forest `size` trees across
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let forest = Forest::filled(size, size, 0).map(|_| rng.below(10) as u8);
+    to_text(&forest)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Forest;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, forest: &Self::Input) -> Result<Answer> {
+        Ok(part_one(forest).into())
+    }
+
+    fn part_two(&self, forest: &Self::Input) -> Result<Answer> {
+        Ok(part_two(forest).into())
+    }
+
+    fn implementations(&self) -> Vec<Implementation<Self::Input>> {
+        vec![
+            Implementation {
+                name: "naive",
+                part: Part::One,
+                solve: |forest| Ok(visible_from_outside(forest).into()),
+            },
+            Implementation {
+                name: "fast",
+                part: Part::One,
+                solve: |forest| Ok(visible_from_outside_sweep(forest).into()),
+            },
+            Implementation {
+                name: "naive",
+                part: Part::Two,
+                solve: |forest| Ok(highest_scenic_score(forest).into()),
+            },
+            Implementation {
+                name: "fast",
+                part: Part::Two,
+                solve: |forest| Ok(highest_scenic_score_sweep(forest).into()),
+            },
+        ]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
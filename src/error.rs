@@ -0,0 +1,131 @@
+//! The error type shared by every day, parse errors point at exactly what couldn't be understood
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    Parse(ParseError),
+    /// The input made sense but there's no answer to be found in it
+    NoAnswer(String),
+    /// The puzzle site, or whatever is standing in for it, couldn't help
+    Remote(String),
+    /// Asked for something the solution doesn't have, like an implementation it doesn't provide
+    Unsupported(String),
+    /// Something that should be impossible, like handing a day another day's parsed input
+    Internal(&'static str),
+}
+
+impl Error {
+    /// Attach the file the input came from to a parse error, other errors are left alone
+    pub fn with_file(self, file: Option<&Path>) -> Self {
+        match self {
+            Self::Parse(err) => Self::Parse(err.with_file(file)),
+            err => err,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+            Self::Parse(err) => write!(f, "{err}"),
+            Self::NoAnswer(reason) => write!(f, "No answer: {reason}"),
+            Self::Remote(reason) | Self::Unsupported(reason) => write!(f, "{reason}"),
+            Self::Internal(reason) => write!(f, "Internal error: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Parse(err) => Some(err),
+            Self::NoAnswer(_) | Self::Remote(_) | Self::Unsupported(_) | Self::Internal(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Name the file in an IO error, which on its own never says which file it was about
+pub(crate) fn in_file(err: std::io::Error, path: &Path) -> std::io::Error {
+    std::io::Error::new(err.kind(), format!("{}: {err}", path.display()))
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+/// Where and why some input couldn't be parsed, lines and columns count from 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    /// The offending piece of input
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error about the `len` bytes of `line` starting at byte `offset`, which must both fall on
+    /// character boundaries. The line number starts at 1, parsers that know better should follow
+    /// up with [`ParseError::on_line`].
+    pub fn at(message: impl Into<String>, line: &str, offset: usize, len: usize) -> Self {
+        let text = line.get(offset..offset + len);
+        debug_assert!(
+            text.is_some(),
+            "{offset}..{} isn't a slice of {line:?}",
+            offset + len
+        );
+        let column = line
+            .get(..offset)
+            .map_or(offset, |before| before.chars().count());
+        Self {
+            file: None,
+            line: 1,
+            column: column + 1,
+            text: text.unwrap_or_default().to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// An error about the whole of `line`
+    pub fn whole_line(message: impl Into<String>, line: &str) -> Self {
+        Self::at(message, line, 0, line.len())
+    }
+
+    /// Set the line number, counting from 1
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub fn with_file(mut self, file: Option<&Path>) -> Self {
+        self.file = file.map(Path::to_path_buf);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}", file.display(), self.line, self.column)?,
+            None => write!(f, "line {}:{}", self.line, self.column)?,
+        }
+        write!(f, ": {}: {:?}", self.message, self.text)
+    }
+}
+
+impl std::error::Error for ParseError {}
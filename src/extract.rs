@@ -0,0 +1,337 @@
+//! Pulling examples, answers and the description out of a saved puzzle page
+//!
+//! The page has an `<article>` per part, each followed by "Your puzzle answer was `...`" once
+//! it's solved. The example is the first `<pre><code>` block in a part, and the example's answer
+//! is the first emphasised bit of code, `<code><em>...</em></code>`, after it. Lists of more
+//! examples follow with answers of their own, so list items only count when they start with the
+//! example itself, the way part two usually gives the new answer for part one's example.
+//!
+//! Only the first block is taken as the example. Later blocks tend to show the example part way
+//! through being solved, like day 5's stacks after each move, which aren't inputs at all.
+use std::path::Path;
+
+use crate::answers::Answers;
+use crate::error::in_file;
+use crate::input::{day_dir, examples_dir, Source};
+use crate::{Part, Result};
+
+/// What could be found for one part of the puzzle
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PartPage {
+    /// The first `<pre><code>` block
+    pub example: Option<String>,
+    /// The first `<code><em>` after the example, most likely the answer for it
+    pub example_answer: Option<String>,
+    /// The answer for the real input, once it's been solved
+    pub answer: Option<String>,
+}
+
+/// Everything useful in a saved puzzle page
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Page {
+    pub title: Option<String>,
+    /// Part one, and part two once it's unlocked
+    pub parts: Vec<PartPage>,
+    /// The description rendered as Markdown
+    pub markdown: String,
+}
+
+impl Page {
+    pub fn parse(html: &str) -> Self {
+        let tokens = tokenize(html);
+        let mut page = Self::default();
+        let mut markdown = Vec::new();
+        let mut idx = 0;
+        while idx < tokens.len() {
+            match tokens[idx] {
+                Token::Open("article", _) => {
+                    let end = tokens[idx..]
+                        .iter()
+                        .position(|token| *token == Token::Close("article"))
+                        .map_or(tokens.len(), |end| idx + end);
+                    let article = &tokens[idx + 1..end];
+                    if page.title.is_none() {
+                        page.title = title(article);
+                    }
+                    let earlier = page
+                        .parts
+                        .iter()
+                        .rev()
+                        .find_map(|part| part.example.as_deref());
+                    let part = part_page(article, earlier);
+                    page.parts.push(part);
+                    markdown.extend_from_slice(article);
+                    idx = end;
+                }
+                Token::Text(text) if text.contains("Your puzzle answer was") => {
+                    // The answer is in the `<code>` straight after
+                    if let (Some(part), Some(Token::Open("code", _)), Some(Token::Text(answer))) = (
+                        page.parts.last_mut(),
+                        tokens.get(idx + 1),
+                        tokens.get(idx + 2),
+                    ) {
+                        part.answer = Some(decode(answer));
+                        markdown.extend_from_slice(&[
+                            Token::Open("p", ""),
+                            tokens[idx],
+                            tokens[idx + 1],
+                            tokens[idx + 2],
+                            Token::Close("code"),
+                            Token::Text("."),
+                            Token::Close("p"),
+                        ]);
+                    }
+                }
+                _ => {}
+            }
+            idx += 1;
+        }
+        page.markdown = render(&markdown);
+        page
+    }
+}
+
+/// Write the examples, fill in answers that aren't recorded yet and save the description as
+/// `puzzle.md`, describing each thing that was done
+///
+/// Existing examples are never overwritten, and neither are answers already in the manifest.
+pub fn extract(page: &Page, input_dir: &Path, day: u8) -> Result<Vec<String>> {
+    let mut done = Vec::new();
+    let mut answers = Answers::load(input_dir, day)?;
+    let mut record = |source: &Source, part: Part, answer: &str| {
+        let recorded = answers.record(input_dir, day, source, part, answer)?;
+        Result::Ok(recorded.then(|| format!("Recorded {answer} for part {part} of {source}")))
+    };
+
+    // An example that only appears in part one is used for both parts
+    let mut current_example = None;
+    for (part, part_page) in [Part::One, Part::Two].into_iter().zip(&page.parts) {
+        if let Some(example) = &part_page.example {
+            let name = format!("part{part}");
+            let path = examples_dir(input_dir, day).join(format!("{name}.txt"));
+            current_example = match std::fs::read_to_string(&path) {
+                Ok(existing) if existing == *example => Some(Source::NamedExample(name)),
+                // The page's answers are for the page's example, not whatever is in the file
+                Ok(_) => {
+                    done.push(format!(
+                        "Left {} as it was, without recording answers for it",
+                        path.display()
+                    ));
+                    None
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                    write(&path, example)?;
+                    done.push(format!("Wrote {}", path.display()));
+                    Some(Source::NamedExample(name))
+                }
+                Err(err) => return Err(in_file(err, &path).into()),
+            };
+        }
+        if let (Some(source), Some(answer)) = (&current_example, &part_page.example_answer) {
+            done.extend(record(source, part, answer)?);
+        }
+        if let Some(answer) = &part_page.answer {
+            done.extend(record(&Source::Real, part, answer)?);
+        }
+    }
+
+    let path = day_dir(input_dir, day).join("puzzle.md");
+    write(&path, &page.markdown)?;
+    done.push(format!("Wrote {}", path.display()));
+    Ok(done)
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    Ok(std::fs::write(path, contents)?)
+}
+
+/// `Tuning Trouble` out of `<h2>--- Day 6: Tuning Trouble ---</h2>`
+fn title(article: &[Token]) -> Option<String> {
+    let heading = article.windows(2).find_map(|pair| match pair {
+        [Token::Open("h2", _), Token::Text(text)] => Some(decode(text)),
+        _ => None,
+    })?;
+    let heading = heading.trim().trim_matches('-').trim();
+    let title = heading.split_once(": ").map_or(heading, |(_, title)| title);
+    Some(title.to_string())
+}
+
+/// The example and its answer, `earlier` is the example from an earlier part for parts that
+/// don't have their own
+fn part_page(article: &[Token], earlier: Option<&str>) -> PartPage {
+    let mut part = PartPage::default();
+    let has_example = article
+        .iter()
+        .any(|token| matches!(token, Token::Open("pre", _)));
+    let (mut in_pre, mut in_code, mut in_em, mut in_li) = (false, 0, 0, 0);
+    let mut after_example = !has_example;
+    let mut example = String::new();
+    // The code a list item starts with, which says which example the item is about
+    let mut item_example = None;
+    let mut item_answer = None;
+    for token in article {
+        match *token {
+            Token::Open("pre", _) => in_pre = true,
+            Token::Close("pre") => {
+                in_pre = false;
+                if part.example.is_none() {
+                    part.example = Some(std::mem::take(&mut example));
+                    after_example = true;
+                }
+            }
+            Token::Open("code", _) => in_code += 1,
+            Token::Close("code") => in_code -= 1,
+            Token::Open("em", _) => in_em += 1,
+            Token::Close("em") => in_em -= 1,
+            Token::Open("li", _) => {
+                in_li += 1;
+                item_example = None;
+            }
+            Token::Close("li") => in_li -= 1,
+            Token::Text(text) if in_pre && part.example.is_none() => {
+                example.push_str(&decode(text));
+            }
+            // Highlighted bits of later blocks aren't answers
+            Token::Text(_) if in_pre => {}
+            Token::Text(text) if in_li > 0 && in_code > 0 && item_example.is_none() => {
+                item_example = Some(decode(text));
+            }
+            Token::Text(text) if after_example && in_code > 0 && in_em > 0 => {
+                if in_li == 0 {
+                    part.example_answer.get_or_insert_with(|| decode(text));
+                } else if item_answer.is_none() {
+                    let example = part.example.as_deref().or(earlier).map(str::trim);
+                    if example.is_some() && item_example.as_deref().map(str::trim) == example {
+                        item_answer = Some(decode(text));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    part.example_answer = part.example_answer.or(item_answer);
+    part
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    /// Tag name and the raw attributes
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+/// Good enough for the puzzle pages, which are tidy, lower case HTML
+fn tokenize(mut html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut skip_until = None;
+    while !html.is_empty() {
+        let Some(lt) = html.find('<') else {
+            tokens.push(Token::Text(html));
+            break;
+        };
+        if lt > 0 && skip_until.is_none() {
+            tokens.push(Token::Text(&html[..lt]));
+        }
+        html = &html[lt..];
+        if let Some(comment) = html.strip_prefix("<!--") {
+            html = comment.split_once("-->").map_or("", |(_, rest)| rest);
+            continue;
+        }
+        let Some(gt) = html.find('>') else {
+            break;
+        };
+        let tag = html[1..gt].trim_end_matches('/');
+        html = &html[gt + 1..];
+        if let Some(name) = tag.strip_prefix('/') {
+            if skip_until == Some(name) {
+                skip_until = None;
+            } else if skip_until.is_none() {
+                tokens.push(Token::Close(name.trim()));
+            }
+        } else if skip_until.is_none() && !tag.starts_with('!') {
+            let (name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            if name == "script" || name == "style" {
+                skip_until = Some(name);
+            } else {
+                tokens.push(Token::Open(name, attributes));
+            }
+        }
+    }
+    tokens
+}
+
+fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The value of an attribute like `href="..."`
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let start = attributes.find(&format!("{name}=\""))? + name.len() + 2;
+    let length = attributes[start..].find('"')?;
+    Some(&attributes[start..start + length])
+}
+
+fn render(tokens: &[Token]) -> String {
+    let mut markdown = String::new();
+    let (mut in_pre, mut in_code) = (false, false);
+    let mut links = Vec::new();
+    for token in tokens {
+        match *token {
+            Token::Open("h2", _) => markdown.push_str("## "),
+            Token::Close("h2" | "p" | "ul") => markdown.push_str("\n\n"),
+            Token::Open("pre", _) => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            Token::Close("pre") => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            }
+            Token::Open("code", _) | Token::Close("code") if !in_pre => {
+                in_code = matches!(token, Token::Open(..));
+                markdown.push('`');
+            }
+            Token::Open("em", _) | Token::Close("em") if !in_pre && !in_code => {
+                markdown.push_str("**")
+            }
+            Token::Open("li", _) => markdown.push_str("- "),
+            Token::Close("li") => markdown.push('\n'),
+            Token::Open("a", attributes) => {
+                links.push(attribute(attributes, "href").unwrap_or_default());
+                markdown.push('[');
+            }
+            Token::Close("a") => {
+                let href = links.pop().unwrap_or_default();
+                markdown.push_str(&format!("]({})", decode(href)));
+            }
+            Token::Text(text) if in_pre => markdown.push_str(&decode(text)),
+            Token::Text(text) => {
+                let text = text.replace('\n', " ");
+                // Whitespace between block tags shouldn't indent the next line
+                let text = if markdown.is_empty() || markdown.ends_with('\n') {
+                    text.trim_start()
+                } else {
+                    &text
+                };
+                markdown.push_str(&decode(text));
+            }
+            _ => {}
+        }
+    }
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+    markdown.trim().to_string() + "\n"
+}
@@ -0,0 +1,80 @@
+//! Random puzzle inputs, for stress testing and for sharing inputs without publishing real ones
+//!
+//! Each day generates its own input in [`Solution::generate`](crate::Solution::generate) from an
+//! [`Rng`], so the same seed and size always give exactly the same input.
+use std::ops::RangeInclusive;
+
+/// splitmix64, small and fast with no dependencies, and plenty random enough for puzzle inputs
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from the range, which mustn't be empty
+    ///
+    /// The modulo skews things very slightly towards the start of huge ranges, which no puzzle
+    /// input is going to notice.
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        assert!(
+            start <= end,
+            "Can't pick from the empty range {start}..={end}"
+        );
+        match (end - start).checked_add(1) {
+            Some(len) => start + (self.next_u64() % len as u64) as usize,
+            None => self.next_u64() as usize,
+        }
+    }
+
+    /// A number from 0 up to but not including `bound`, which mustn't be 0
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "Can't pick a number below 0");
+        self.range(0..=bound - 1)
+    }
+
+    /// True one time in `times`
+    pub fn one_in(&mut self, times: usize) -> bool {
+        self.below(times) == 0
+    }
+
+    /// Any one of the items, there must be at least one
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Any one of the characters in `chars`, there must be at least one
+    pub fn char(&mut self, chars: &str) -> char {
+        let count = chars.chars().count();
+        match chars.chars().nth(self.below(count)) {
+            Some(c) => c,
+            None => unreachable!("below is less than the count"),
+        }
+    }
+
+    /// Put the items in a random order
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        // Fisher-Yates
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.range(0..=idx));
+        }
+    }
+}
+
+/// `a` to `z`, which most puzzles use for names and labels
+pub const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+
+/// `A` to `Z`
+pub const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
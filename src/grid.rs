@@ -0,0 +1,299 @@
+//! A rectangular grid of cells, for the puzzles that come as a map
+//!
+//! Points are `(x, y)` with the origin in the top left, x grows to the east and y to the south.
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use crate::parse;
+use crate::ParseError;
+
+/// `(x, y)` of a cell
+pub type Point = (usize, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The four directions that don't go diagonally
+    pub const ORTHOGONAL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// Every direction, clockwise from north
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// How far one step in this direction moves along x and y
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Self::North => (0, -1),
+            Self::NorthEast => (1, -1),
+            Self::East => (1, 0),
+            Self::SouthEast => (1, 1),
+            Self::South => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (-1, -1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// Row after row
+    cells: Vec<T>,
+}
+
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+        Self {
+            width: 0,
+            height: 0,
+            cells: Vec::new(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid with every cell set to `value`
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Flip over the diagonal from the top left, so rows become columns
+    pub fn transpose(&self) -> Self {
+        self.rearrange(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    /// Turn a quarter turn clockwise
+    pub fn rotate_clockwise(&self) -> Self {
+        self.rearrange(self.height, self.width, |(x, y)| (y, self.height - 1 - x))
+    }
+
+    /// Turn a quarter turn anticlockwise
+    pub fn rotate_anticlockwise(&self) -> Self {
+        self.rearrange(self.height, self.width, |(x, y)| (self.width - 1 - y, x))
+    }
+
+    /// A new grid where each point takes the cell from `source(point)` in this one
+    fn rearrange(&self, width: usize, height: usize, source: impl Fn(Point) -> Point) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|point| self[source(point)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from its cells row after row, as long as they fill whole rows
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Option<Self> {
+        if width == 0 {
+            return cells.is_empty().then(Self::default);
+        }
+        (cells.len() % width == 0).then(|| Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// One row per line and one cell per character, turned into a cell by `cell`
+    ///
+    /// Every row must be the same width, and anything `cell` refuses is reported with its message.
+    pub fn parse<F, E>(input: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Result<T, E>,
+        E: Display,
+    {
+        let mut grid = Self::default();
+        for line in parse::lines(input) {
+            let before = grid.cells.len();
+            for (col, c) in line.text.char_indices() {
+                let value =
+                    cell(c).map_err(|err| line.error(err.to_string(), col, c.len_utf8()))?;
+                grid.cells.push(value);
+            }
+            let width = grid.cells.len() - before;
+            if grid.height == 0 {
+                grid.width = width;
+            } else if width != grid.width {
+                return Err(line
+                    .whole()
+                    .error(format!("Expected a row {} wide, found {width}", grid.width)));
+            }
+            grid.height += 1;
+        }
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Point) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (x, y): Point) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// The point one step away in `direction`, if it's still on the grid
+    pub fn step(&self, (x, y): Point, direction: Direction) -> Option<Point> {
+        let (dx, dy) = direction.offset();
+        // Stepping off the west or north edge wraps around to huge numbers, which are off the grid
+        let (x, y) = (x.wrapping_add(dx as usize), y.wrapping_add(dy as usize));
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    /// Every point, row after row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its point, row after row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The cells directly north, east, south and west, where there are any
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.around(point, &Direction::ORTHOGONAL)
+    }
+
+    /// All eight surrounding cells including the diagonals, where there are any
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.around(point, &Direction::ALL)
+    }
+
+    fn around<'a>(
+        &'a self,
+        point: Point,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = (Point, &'a T)> {
+        directions
+            .iter()
+            .filter_map(move |&direction| self.step(point, direction))
+            .map(|point| (point, &self[point]))
+    }
+
+    /// Cells from next to `start` heading in `direction` until the edge, not including `start`
+    pub fn ray(&self, start: Point, direction: Direction) -> impl Iterator<Item = (Point, &T)> {
+        std::iter::successors(self.step(start, direction), move |&point| {
+            self.step(point, direction)
+        })
+        .map(|point| (point, &self[point]))
+    }
+
+    /// Row `y`, west to east
+    ///
+    /// # Panics
+    ///
+    /// If there's no row `y`.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "Row {y} is outside the grid");
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Column `x`, north to south
+    ///
+    /// # Panics
+    ///
+    /// If there's no column `x`.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {x} is outside the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Every row, north to south
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Every column, west to east
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The same shape of grid with `f` applied to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+/// Panics when the point is outside the grid, [`Grid::get`] is the checked version
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!(
+                "{point:?} is outside the {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(point) {
+            Some(cell) => cell,
+            None => panic!("{point:?} is outside the {width}x{height} grid"),
+        }
+    }
+}
+
+/// One line per row with the cells written back to back
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+//! Just enough of an HTTP client to fetch inputs and submit answers
+//!
+//! Plain `http://` URLs are spoken to directly over TCP, which is all a local stand-in for the
+//! site needs. There's no TLS here, so `https://` URLs are handed to `curl`.
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use crate::{Error, Result};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The site asks automated tools to say what they are
+const USER_AGENT: &str = concat!("aoc_2022/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// `GET` the URL with some extra headers
+pub fn get(url: &str, headers: &[(&str, &str)]) -> Result<Response> {
+    send("GET", url, headers, None)
+}
+
+/// `POST` the fields to the URL as a form
+pub fn post_form(url: &str, headers: &[(&str, &str)], fields: &[(&str, &str)]) -> Result<Response> {
+    let body = fields
+        .iter()
+        .map(|(key, value)| format!("{}={}", encode(key), encode(value)))
+        .collect::<Vec<_>>()
+        .join("&");
+    send("POST", url, headers, Some(&body))
+}
+
+fn send(method: &str, url: &str, headers: &[(&str, &str)], body: Option<&str>) -> Result<Response> {
+    if let Some(rest) = url.strip_prefix("http://") {
+        send_plain(method, rest, headers, body)
+    } else if url.starts_with("https://") {
+        send_curl(url, headers, body)
+    } else {
+        Err(Error::Remote(format!("Unsupported URL {url:?}")))
+    }
+}
+
+/// Speak HTTP/1.1 directly, `rest` is the URL without its `http://`
+fn send_plain(
+    method: &str,
+    rest: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response> {
+    let (authority, path) = match rest.find('/') {
+        Some(idx) => rest.split_at(idx),
+        None => (rest, "/"),
+    };
+    let address = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{authority}:80")
+    };
+    let mut stream = TcpStream::connect(&address)
+        .map_err(|err| Error::Remote(format!("Couldn't connect to {address}: {err}")))?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    let mut request = format!("{method} {path} HTTP/1.1\r\nHost: {authority}\r\n");
+    request.push_str(&format!(
+        "User-Agent: {USER_AGENT}\r\nConnection: close\r\n"
+    ));
+    for (name, value) in headers {
+        request.push_str(&format!("{name}: {value}\r\n"));
+    }
+    if let Some(body) = body {
+        request.push_str("Content-Type: application/x-www-form-urlencoded\r\n");
+        request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    request.push_str("\r\n");
+    request.push_str(body.unwrap_or_default());
+    stream.write_all(request.as_bytes())?;
+
+    let mut raw = Vec::new();
+    stream.read_to_end(&mut raw)?;
+    parse_response(&String::from_utf8_lossy(&raw))
+}
+
+fn parse_response(raw: &str) -> Result<Response> {
+    let malformed = || Error::Remote("Malformed HTTP response".to_string());
+    let (head, body) = raw.split_once("\r\n\r\n").ok_or_else(malformed)?;
+    let mut lines = head.split("\r\n");
+    let status = lines
+        .next()
+        .and_then(|line| line.split(' ').nth(1))
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(malformed)?;
+    let chunked = lines.any(|line| {
+        let line = line.to_ascii_lowercase();
+        line.starts_with("transfer-encoding:") && line.contains("chunked")
+    });
+    let body = if chunked {
+        dechunk(body).ok_or_else(malformed)?
+    } else {
+        body.to_string()
+    };
+    Ok(Response { status, body })
+}
+
+/// Stitch a chunked body back together
+fn dechunk(mut body: &str) -> Option<String> {
+    let mut joined = String::new();
+    loop {
+        let (size, rest) = body.split_once("\r\n")?;
+        let size = usize::from_str_radix(size.split(';').next()?.trim(), 16).ok()?;
+        if size == 0 {
+            return Some(joined);
+        }
+        joined.push_str(rest.get(..size)?);
+        body = rest.get(size..)?.strip_prefix("\r\n")?;
+    }
+}
+
+/// Let `curl` deal with TLS, headers go in on stdin to keep the session cookie off the command line
+fn send_curl(url: &str, headers: &[(&str, &str)], body: Option<&str>) -> Result<Response> {
+    let mut command = Command::new("curl");
+    command
+        .args(["--silent", "--show-error", "--header", "@-"])
+        .args(["--user-agent", USER_AGENT])
+        .args(["--write-out", "\n%{http_code}"]);
+    if let Some(body) = body {
+        command.args(["--data-raw", body]);
+    }
+    let mut child = command
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| Error::Remote(format!("Couldn't run curl for an https URL: {err}")))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        for (name, value) in headers {
+            writeln!(stdin, "{name}: {value}")?;
+        }
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(Error::Remote(format!(
+            "curl failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = stdout.rsplit_once('\n').unwrap_or(("", &stdout));
+    let status = status
+        .trim()
+        .parse()
+        .map_err(|_| Error::Remote(format!("curl gave no status code for {url}")))?;
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+/// Percent encode everything but the characters that never need it
+fn encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
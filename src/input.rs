@@ -0,0 +1,167 @@
+//! Where a day's puzzle input comes from
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::error::in_file;
+
+/// Default directory holding the `NN/example.txt`, `NN/examples/*.txt` and `NN/real.txt` files
+pub const DEFAULT_DIR: &str = "input";
+
+/// The `--input-dir` argument of every command that reads inputs or answers
+#[derive(clap::Args, Debug, Clone)]
+pub struct InputDir {
+    /// Directory holding the per day input directories
+    #[arg(id = "input_dir", long = "input-dir", value_name = "DIR", default_value = DEFAULT_DIR)]
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// `<dir>/NN/example.txt`
+    Example,
+    /// `<dir>/NN/examples/<name>.txt`, for puzzles with more than one example
+    NamedExample(String),
+    /// `<dir>/NN/real.txt`
+    Real,
+    /// An explicit file, the day is ignored
+    File(PathBuf),
+    /// Whatever is piped in
+    Stdin,
+}
+
+impl Source {
+    /// Path of the file backing this source, stdin has none
+    pub fn path(&self, dir: &Path, day: u8) -> Option<PathBuf> {
+        match self {
+            Self::Example => Some(day_dir(dir, day).join("example.txt")),
+            Self::NamedExample(name) => Some(examples_dir(dir, day).join(format!("{name}.txt"))),
+            Self::Real => Some(day_dir(dir, day).join("real.txt")),
+            Self::File(path) => Some(path.clone()),
+            Self::Stdin => None,
+        }
+    }
+
+    /// Read and [`normalise`] the input, keeping any trailing whitespace
+    pub fn read(&self, dir: &Path, day: u8) -> std::io::Result<String> {
+        Ok(self.read_with(dir, day, Trailing::Keep)?.text)
+    }
+
+    /// Read and [`normalise`] the input, dealing with trailing whitespace as asked
+    pub fn read_with(
+        &self,
+        dir: &Path,
+        day: u8,
+        trailing: Trailing,
+    ) -> std::io::Result<Normalised> {
+        let raw = match self.path(dir, day) {
+            Some(path) => std::fs::read_to_string(&path).map_err(|err| in_file(err, &path))?,
+            None => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)?;
+                input
+            }
+        };
+        Ok(normalise(&raw, trailing))
+    }
+}
+
+/// Short label for reports, the file name for explicit files
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Example => write!(f, "example"),
+            Self::NamedExample(name) => write!(f, "example:{name}"),
+            Self::Real => write!(f, "real"),
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+/// What to do about whitespace at the end of lines, which is invisible in most editors
+#[derive(clap::ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Trailing {
+    /// Leave it for the day to deal with
+    #[default]
+    Keep,
+    /// Leave it, but note which lines have it
+    Report,
+    /// Remove it
+    Strip,
+}
+
+/// Input with the differences between editors and platforms smoothed over
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Normalised {
+    pub text: String,
+    /// Lines ending in whitespace, counting from 1, only filled in by [`Trailing::Report`]
+    pub trailing: Vec<usize>,
+}
+
+/// Drop any byte order mark and turn `\r\n` and lone `\r` line endings into `\n`, so every day
+/// can split lines and count columns without worrying about where the input was saved
+pub fn normalise(raw: &str, trailing: Trailing) -> Normalised {
+    let raw = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+    let mut text = raw.replace("\r\n", "\n").replace('\r', "\n");
+    let mut lines_with_trailing = Vec::new();
+    match trailing {
+        Trailing::Keep => {}
+        Trailing::Report => {
+            lines_with_trailing = text
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| line.ends_with(char::is_whitespace))
+                .map(|(idx, _)| idx + 1)
+                .collect();
+        }
+        Trailing::Strip => {
+            let ends_with_newline = text.ends_with('\n');
+            text = text
+                .lines()
+                .map(str::trim_end)
+                .collect::<Vec<_>>()
+                .join("\n");
+            if ends_with_newline {
+                text.push('\n');
+            }
+        }
+    }
+    Normalised {
+        text,
+        trailing: lines_with_trailing,
+    }
+}
+
+/// `<dir>/NN`
+pub fn day_dir(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("{day:02}"))
+}
+
+/// `<dir>/NN/examples`
+pub fn examples_dir(dir: &Path, day: u8) -> PathBuf {
+    day_dir(dir, day).join("examples")
+}
+
+/// The main example followed by any named ones, in name order
+pub fn examples(dir: &Path, day: u8) -> std::io::Result<Vec<Source>> {
+    let examples_dir = examples_dir(dir, day);
+    let entries = match std::fs::read_dir(&examples_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(vec![Source::Example]),
+        Err(err) => return Err(in_file(err, &examples_dir)),
+    };
+    let mut names = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().map_or(false, |ext| ext == "txt") {
+            if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names.sort();
+    Ok(std::iter::once(Source::Example)
+        .chain(names.into_iter().map(Source::NamedExample))
+        .collect())
+}
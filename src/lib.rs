@@ -1,11 +1,148 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
 pub use clap::Parser;
 
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+pub mod config;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+mod error;
+pub mod extract;
+pub mod gen;
+pub mod grid;
+mod http;
+pub mod input;
+pub mod parse;
+pub mod remote;
+pub mod report;
+pub mod scaffold;
+pub mod solution;
+pub mod status;
+mod toml;
+pub mod trace;
+pub mod watch;
+
+pub use error::{Error, ParseError, Result};
+pub use input::Source;
+pub use solution::{Answer, Solution};
+
 #[derive(Parser, Debug)]
 pub struct Args {
     /// Use real data instead of example data input file(s)
-    #[arg(short = 'r', long)]
+    #[arg(short = 'r', long, conflicts_with_all = ["input", "example"])]
     pub real: bool,
 
-    #[arg(short = '2', long)]
-    pub part_two: bool,
+    /// Use `examples/<NAME>.txt` instead of the main example
+    #[arg(short = 'e', long, value_name = "NAME", conflicts_with = "input")]
+    pub example: Option<String>,
+
+    /// Read the puzzle input from this file instead, use `-` for stdin
+    #[arg(short = 'i', long, value_name = "PATH")]
+    pub input: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub input_dir: input::InputDir,
+
+    /// Which part of the puzzle to solve, both parts share one parse of the input [default: 1,
+    /// or both when benchmarking]
+    #[arg(short = 'p', long, value_enum)]
+    pub part: Option<Parts>,
+
+    /// Solve with one of the day's named implementations instead of the usual one, `aoc list` shows
+    /// which there are
+    #[arg(long = "impl", value_name = "NAME")]
+    pub implementation: Option<String>,
+
+    /// Show what the day is doing on stderr, twice for more detail
+    #[arg(short = 'v', long = "verbose", action = clap::ArgAction::Count)]
+    pub verbosity: u8,
+
+    /// What to do about whitespace at the end of input lines
+    #[arg(long, value_enum, value_name = "HOW", default_value_t = input::Trailing::Keep)]
+    pub trailing_whitespace: input::Trailing,
+}
+
+impl Args {
+    /// Where the input should be read from
+    pub fn source(&self) -> Source {
+        match &self.input {
+            Some(path) if path.as_os_str() == "-" => Source::Stdin,
+            Some(path) => Source::File(path.clone()),
+            None if self.real => Source::Real,
+            None => match &self.example {
+                Some(name) => Source::NamedExample(name.clone()),
+                None => Source::Example,
+            },
+        }
+    }
+
+    /// The selected parts, or the given default when none were asked for
+    pub fn parts(&self, default: Parts) -> &'static [Part] {
+        self.part.unwrap_or(default).parts()
+    }
+
+    /// Read the input for the given day from wherever the arguments point, warning on stderr
+    /// about trailing whitespace when asked to report it
+    pub fn read_input(&self, day: u8) -> std::io::Result<String> {
+        let source = self.source();
+        let input = source.read_with(&self.input_dir.path, day, self.trailing_whitespace)?;
+        if !input.trailing.is_empty() {
+            let lines = input
+                .trailing
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>();
+            eprintln!(
+                "Warning: trailing whitespace in {source} on line(s) {}",
+                lines.join(", ")
+            );
+        }
+        Ok(input.text)
+    }
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// The parts selected on the command line
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parts {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+impl Parts {
+    pub fn parts(self) -> &'static [Part] {
+        match self {
+            Self::One => &[Part::One],
+            Self::Two => &[Part::Two],
+            Self::Both => &[Part::One, Part::Two],
+        }
+    }
 }
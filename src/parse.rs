@@ -0,0 +1,219 @@
+//! Building blocks for the shapes puzzle input keeps coming in
+//!
+//! Everything works on [`Line`]s and the [`Piece`]s cut from them, which remember where they came
+//! from, so whatever goes wrong the error points at the exact line and column.
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::ParseError;
+
+type Result<T> = std::result::Result<T, ParseError>;
+
+/// One line of input along with its line number, counting from 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Every line of the input, numbered
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(idx, text)| Line {
+        number: idx + 1,
+        text,
+    })
+}
+
+/// Runs of lines separated by blank lines, any number of blank lines in a row count as one
+pub fn groups(input: &str) -> impl Iterator<Item = Vec<Line<'_>>> {
+    let mut lines = lines(input).peekable();
+    std::iter::from_fn(move || {
+        while lines.next_if(|line| line.text.trim().is_empty()).is_some() {}
+        let group = std::iter::from_fn(|| lines.next_if(|line| !line.text.trim().is_empty()))
+            .collect::<Vec<_>>();
+        (!group.is_empty()).then_some(group)
+    })
+}
+
+/// Cut a diagram drawn in fixed-width columns into the cells of each column, top to bottom
+///
+/// Rows that stop short just don't have cells in the later columns, so columns can be shorter
+/// than the diagram is tall.
+pub fn columns<'a>(rows: &[Line<'a>], width: usize) -> Vec<Vec<Piece<'a>>> {
+    let mut columns = Vec::<Vec<_>>::new();
+    for row in rows {
+        for (idx, cell) in row.cells(width).enumerate() {
+            if columns.len() <= idx {
+                columns.resize_with(idx + 1, Vec::new);
+            }
+            columns[idx].push(cell);
+        }
+    }
+    columns
+}
+
+impl<'a> Line<'a> {
+    /// An error about the `len` bytes of this line starting at byte `offset`
+    pub fn error(&self, message: impl Into<String>, offset: usize, len: usize) -> ParseError {
+        ParseError::at(message, self.text, offset, len).on_line(self.number)
+    }
+
+    /// The whole line as a piece, to cut up further
+    pub fn whole(&self) -> Piece<'a> {
+        Piece {
+            line: *self,
+            offset: 0,
+            text: self.text,
+        }
+    }
+
+    /// Match the whole line against a template like `move {} from {} to {}`, giving whatever
+    /// stood in for each `{}`
+    ///
+    /// Each `{}` takes everything up to the next piece of literal text and can't be empty, so two
+    /// of them in a row will never match.
+    ///
+    /// # Panics
+    ///
+    /// If the template doesn't have exactly `N` placeholders.
+    pub fn template<const N: usize>(&self, template: &str) -> Result<[Piece<'a>; N]> {
+        let placeholders = template.matches("{}").count();
+        assert_eq!(
+            placeholders, N,
+            "Template {template:?} has {placeholders} placeholders, not {N}"
+        );
+        let mut literals = template.split("{}");
+        let first = literals.next().unwrap_or_default();
+        let Some(mut rest) = self.text.strip_prefix(first) else {
+            return Err(self.whole().error(format!("Expected {first:?}")));
+        };
+        // Where in the line `rest` starts
+        let rest_at = |rest: &str| self.text.len() - rest.len();
+        let mut captures = Vec::with_capacity(N);
+        for literal in literals {
+            let end = if literal.is_empty() {
+                rest.len()
+            } else {
+                rest.find(literal).ok_or_else(|| {
+                    self.error(format!("Expected {literal:?}"), rest_at(rest), rest.len())
+                })?
+            };
+            let capture = self.piece(rest_at(rest), end);
+            if capture.text.is_empty() && !literal.is_empty() {
+                return Err(capture.error("Expected a value"));
+            }
+            captures.push(capture);
+            rest = &rest[end + literal.len()..];
+        }
+        if let Some(capture) = captures.last().filter(|capture| capture.text.is_empty()) {
+            return Err(capture.error("Expected a value"));
+        }
+        if !rest.is_empty() {
+            return Err(self.error("Unexpected trailing text", rest_at(rest), rest.len()));
+        }
+        match captures.try_into() {
+            Ok(captures) => Ok(captures),
+            Err(_) => unreachable!("there's one capture per placeholder"),
+        }
+    }
+
+    /// The cells of this line when it's drawn in columns `width` characters wide, the last cell
+    /// is whatever's left over and may be narrower
+    pub fn cells(&self, width: usize) -> impl Iterator<Item = Piece<'a>> {
+        assert!(width > 0, "Columns must be at least one character wide");
+        let line = *self;
+        let mut offset = 0;
+        std::iter::from_fn(move || {
+            let rest = &line.text[offset..];
+            if rest.is_empty() {
+                return None;
+            }
+            let len = rest
+                .char_indices()
+                .nth(width)
+                .map_or(rest.len(), |(idx, _)| idx);
+            let cell = line.piece(offset, len);
+            offset += len;
+            Some(cell)
+        })
+    }
+
+    /// The `len` bytes starting at byte `offset`
+    fn piece(&self, offset: usize, len: usize) -> Piece<'a> {
+        Piece {
+            line: *self,
+            offset,
+            text: &self.text[offset..offset + len],
+        }
+    }
+}
+
+/// Part of a [`Line`] that knows where in the line it is, so errors about it point at its column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Piece<'a> {
+    pub line: Line<'a>,
+    /// Where the piece starts in the line, in bytes
+    pub offset: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Piece<'a> {
+    /// An error about this piece
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        self.line.error(message, self.offset, self.text.len())
+    }
+
+    /// The piece without whitespace at either end
+    pub fn trim(&self) -> Self {
+        let start = self.text.len() - self.text.trim_start().len();
+        self.line.piece(self.offset + start, self.text.trim().len())
+    }
+
+    /// Parse the piece as a number, or anything else that implements [`FromStr`]
+    pub fn integer<T>(&self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.text
+            .parse()
+            .map_err(|err| self.error(format!("Invalid number ({err})")))
+    }
+
+    /// Split the piece into exactly `N` pieces around `delimiter`
+    pub fn split<const N: usize>(&self, delimiter: &str) -> Result<[Piece<'a>; N]> {
+        let mut offset = self.offset;
+        let pieces = self
+            .text
+            .split(delimiter)
+            .map(|text| {
+                let piece = self.line.piece(offset, text.len());
+                offset += text.len() + delimiter.len();
+                piece
+            })
+            .collect::<Vec<_>>();
+        pieces.try_into().map_err(|pieces: Vec<_>| {
+            self.error(format!(
+                "Expected {N} values separated by {delimiter:?}, found {}",
+                pieces.len()
+            ))
+        })
+    }
+
+    /// Split the piece into exactly `N` numbers around `delimiter`, like `2-4` or `3,5,7`
+    pub fn integers<T, const N: usize>(&self, delimiter: &str) -> Result<[T; N]>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let numbers = self
+            .split::<N>(delimiter)?
+            .iter()
+            .map(Piece::integer)
+            .collect::<Result<Vec<T>>>()?;
+        match numbers.try_into() {
+            Ok(numbers) => Ok(numbers),
+            Err(_) => unreachable!("split always gives exactly N pieces"),
+        }
+    }
+}
@@ -0,0 +1,308 @@
+//! Fetching inputs from and submitting answers to the puzzle site, or whatever the config points
+//! at instead
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config::Config;
+use crate::error::in_file;
+use crate::input::day_dir;
+use crate::{http, Answer, Error, Part, Result, Source};
+
+/// Every submission for a day is kept in `input/NN/submissions.tsv`
+pub const HISTORY_FILE: &str = "submissions.tsv";
+
+/// What [`fetch`] did to get the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// It was already on disk, nothing was downloaded
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Make sure the real input for a day is on disk, downloading it only if it isn't
+///
+/// An empty file, like the placeholder `aoc new` leaves, doesn't count as having the input.
+pub fn fetch(config: &Config, input_dir: &Path, day: u8) -> Result<Fetched> {
+    let path = Source::Real
+        .path(input_dir, day)
+        .ok_or(Error::Internal("The real input has no path"))?;
+    if std::fs::metadata(&path).map_or(false, |meta| meta.len() > 0) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let cookie = format!("session={}", config.session()?);
+    let url = config.url(&format!("day/{day}/input"));
+    let response = http::get(&url, &[("Cookie", &cookie)])?;
+    if response.status != 200 {
+        let reason = response.body.lines().next().unwrap_or_default();
+        return Err(Error::Remote(format!(
+            "Fetching {url} failed with status {}: {reason}",
+            response.status
+        )));
+    }
+
+    // Write it alongside first so a failure can't leave a partial input that looks cached
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let partial = path.with_extension("txt.partial");
+    std::fs::write(&partial, response.body)?;
+    std::fs::rename(&partial, &path)?;
+    Ok(Fetched::Downloaded(path))
+}
+
+/// What the site made of a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Holds how long the site says to wait, when it says
+    RateLimited(Option<String>),
+    /// The part was already solved, or isn't unlocked yet
+    WrongLevel,
+    /// Holds the first line of the response
+    Unrecognised(String),
+}
+
+impl Verdict {
+    /// Pick the verdict out of the page the site responds with
+    pub fn from_response(body: &str) -> Self {
+        let text = body.to_ascii_lowercase();
+        if text.contains("that's the right answer") {
+            Self::Right
+        } else if text.contains("answer too recently") {
+            let wait = text
+                .find("you have ")
+                .map(|idx| &body[idx + "you have ".len()..])
+                .and_then(|rest| rest.split(" left to wait").next())
+                .map(str::to_string);
+            Self::RateLimited(wait)
+        } else if text.contains("your answer is too high") {
+            Self::TooHigh
+        } else if text.contains("your answer is too low") {
+            Self::TooLow
+        } else if text.contains("not the right answer") {
+            Self::Wrong
+        } else if text.contains("solving the right level") {
+            Self::WrongLevel
+        } else {
+            Self::Unrecognised(body.trim().lines().next().unwrap_or_default().to_string())
+        }
+    }
+
+    /// Whether the answer is definitely wrong, so there's no point trying it again
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Self::Wrong | Self::TooHigh | Self::TooLow)
+    }
+
+    /// Short name for the history file
+    fn label(&self) -> &str {
+        match self {
+            Self::Right => "right",
+            Self::Wrong => "wrong",
+            Self::TooHigh => "too-high",
+            Self::TooLow => "too-low",
+            Self::RateLimited(_) => "rate-limited",
+            Self::WrongLevel => "wrong-level",
+            Self::Unrecognised(_) => "unrecognised",
+        }
+    }
+
+    fn from_label(label: &str) -> Option<Self> {
+        Some(match label {
+            "right" => Self::Right,
+            "wrong" => Self::Wrong,
+            "too-high" => Self::TooHigh,
+            "too-low" => Self::TooLow,
+            "rate-limited" => Self::RateLimited(None),
+            "wrong-level" => Self::WrongLevel,
+            "unrecognised" => Self::Unrecognised(String::new()),
+            _ => return None,
+        })
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Right => write!(f, "That's the right answer"),
+            Self::Wrong => write!(f, "That's not the right answer"),
+            Self::TooHigh => write!(f, "Wrong, the answer is too high"),
+            Self::TooLow => write!(f, "Wrong, the answer is too low"),
+            Self::RateLimited(Some(wait)) => write!(f, "Rate limited, {wait} left to wait"),
+            Self::RateLimited(None) => write!(f, "Rate limited"),
+            Self::WrongLevel => write!(f, "That part is already solved or not unlocked yet"),
+            Self::Unrecognised(text) => write!(f, "Unrecognised response: {text}"),
+        }
+    }
+}
+
+/// One answer that was sent to the site
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    /// Seconds since the Unix epoch
+    pub time: u64,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Everything submitted for one day, oldest first
+///
+/// The file has a line per submission with the time, part, answer and verdict separated by tabs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct History {
+    path: PathBuf,
+    pub submissions: Vec<Submission>,
+}
+
+impl History {
+    /// Load a day's history, there's nothing in it if the file doesn't exist yet
+    pub fn load(input_dir: &Path, day: u8) -> Result<Self> {
+        let path = day_dir(input_dir, day).join(HISTORY_FILE);
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(in_file(err, &path).into()),
+        };
+        let submissions = crate::parse::lines(&text)
+            .map(|line| {
+                let parse = || {
+                    let mut fields = line.text.split('\t');
+                    let time = fields.next()?.parse().ok()?;
+                    let part = match fields.next()? {
+                        "1" => Part::One,
+                        "2" => Part::Two,
+                        _ => return None,
+                    };
+                    let answer = fields.next()?.to_string();
+                    let verdict = Verdict::from_label(fields.next()?)?;
+                    Some(Submission {
+                        time,
+                        part,
+                        answer,
+                        verdict,
+                    })
+                };
+                parse().ok_or_else(|| {
+                    line.whole()
+                        .error("Expected time, part, answer and verdict")
+                        .with_file(Some(&path))
+                })
+            })
+            .collect::<std::result::Result<_, _>>()?;
+        Ok(Self { path, submissions })
+    }
+
+    /// Why the answer shouldn't be submitted given what's already known, if there's a reason
+    pub fn objection(&self, part: Part, answer: &str) -> Option<String> {
+        let earlier = || self.submissions.iter().filter(|sub| sub.part == part);
+        if let Some(right) = earlier().find(|sub| sub.verdict == Verdict::Right) {
+            return Some(format!(
+                "Part {part} was already solved with {}",
+                right.answer
+            ));
+        }
+        if let Some(wrong) = earlier().find(|sub| sub.answer == answer && sub.verdict.is_wrong()) {
+            return Some(format!("{answer} was already tried: {}", wrong.verdict));
+        }
+        let number = answer.parse::<i128>().ok()?;
+        let bound = |verdict: Verdict| {
+            earlier()
+                .filter(move |sub| sub.verdict == verdict)
+                .filter_map(|sub| sub.answer.parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Verdict::TooHigh).filter(|&high| number >= high).min() {
+            return Some(format!(
+                "{answer} can't be right, {high} was already too high"
+            ));
+        }
+        if let Some(low) = bound(Verdict::TooLow).filter(|&low| number <= low).max() {
+            return Some(format!(
+                "{answer} can't be right, {low} was already too low"
+            ));
+        }
+        None
+    }
+
+    /// Add a submission to the end of the file
+    pub fn record(&mut self, submission: Submission) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}",
+            submission.time,
+            submission.part,
+            submission.answer,
+            submission.verdict.label()
+        )?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+}
+
+/// What happened to an answer given to [`submit`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submitted {
+    /// It wasn't sent, the history already rules it out
+    Refused(String),
+    Judged(Verdict),
+}
+
+/// Send an answer to the site unless the history shows there's no point, recording the verdict
+pub fn submit(
+    config: &Config,
+    input_dir: &Path,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<Submitted> {
+    let answer = answer.to_string();
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        return Ok(Submitted::Refused(format!(
+            "{answer:?} doesn't look like an answer"
+        )));
+    }
+    let mut history = History::load(input_dir, day)?;
+    if let Some(objection) = history.objection(part, &answer) {
+        return Ok(Submitted::Refused(objection));
+    }
+
+    let cookie = format!("session={}", config.session()?);
+    let url = config.url(&format!("day/{day}/answer"));
+    let level = part.to_string();
+    let response = http::post_form(
+        &url,
+        &[("Cookie", &cookie)],
+        &[("level", &level), ("answer", &answer)],
+    )?;
+    if response.status != 200 {
+        let reason = response.body.lines().next().unwrap_or_default();
+        return Err(Error::Remote(format!(
+            "Submitting to {url} failed with status {}: {reason}",
+            response.status
+        )));
+    }
+
+    let verdict = Verdict::from_response(&response.body);
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+    history.record(Submission {
+        time,
+        part,
+        answer,
+        verdict: verdict.clone(),
+    })?;
+    Ok(Submitted::Judged(verdict))
+}
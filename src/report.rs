@@ -0,0 +1,89 @@
+//! Output formats for answers and timings
+use std::fmt::Write;
+use std::time::Duration;
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// Plain answers and tables meant for people
+    #[default]
+    Text,
+    /// One JSON object per line meant for other programs
+    Json,
+}
+
+/// Builds a single line JSON object, just enough JSON to avoid pulling in a serialisation crate
+#[derive(Debug, Clone)]
+pub struct JsonObject {
+    buf: String,
+}
+
+impl Default for JsonObject {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JsonObject {
+    pub fn new() -> Self {
+        Self {
+            buf: String::from("{"),
+        }
+    }
+
+    fn key(&mut self, key: &str) {
+        if self.buf.len() > 1 {
+            self.buf.push(',');
+        }
+        write_str(&mut self.buf, key);
+        self.buf.push(':');
+    }
+
+    pub fn str(mut self, key: &str, value: &str) -> Self {
+        self.key(key);
+        write_str(&mut self.buf, value);
+        self
+    }
+
+    pub fn num(mut self, key: &str, value: impl Into<u128>) -> Self {
+        self.key(key);
+        write!(self.buf, "{}", value.into()).unwrap();
+        self
+    }
+
+    pub fn nanos(self, key: &str, value: Duration) -> Self {
+        self.num(key, value.as_nanos())
+    }
+
+    pub fn bool(mut self, key: &str, value: bool) -> Self {
+        self.key(key);
+        self.buf.push_str(if value { "true" } else { "false" });
+        self
+    }
+
+    pub fn null(mut self, key: &str) -> Self {
+        self.key(key);
+        self.buf.push_str("null");
+        self
+    }
+
+    pub fn finish(mut self) -> String {
+        self.buf.push('}');
+        self.buf
+    }
+}
+
+fn write_str(buf: &mut String, value: &str) {
+    buf.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(buf, "\\u{:04x}", c as u32).unwrap(),
+            c => buf.push(c),
+        }
+    }
+    buf.push('"');
+}
@@ -0,0 +1,208 @@
+//! Everything a new day needs before the puzzle can be solved, behind `aoc new`
+//!
+//! New files are only ever created, never overwritten, and the new day is wired into `src/lib.rs`
+//! and the registry in `src/solution.rs` by adding lines next to the existing days.
+use std::io::{Error, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use crate::error::in_file;
+use crate::input::{self, day_dir};
+
+const MODULE_TEMPLATE: &str = r#"//! # Day {day}: {title}
+use crate::gen::Rng;
+use crate::{Answer, Result, Solution};
+
+/// One line per entry for now
+pub fn parse(input: &str) -> Result<Vec<String>> {
+    Ok(input.lines().map(str::to_string).collect())
+}
+
+/// Placeholder that just counts the lines
+pub fn part_one(lines: &[String]) -> usize {
+    lines.len()
+}
+
+/// Placeholder that just counts the lines
+pub fn part_two(lines: &[String]) -> usize {
+    lines.len()
+}
+
+/// Placeholder that makes `size` lines of numbers
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| format!("{}\n", rng.below(1000))).collect()
+}
+
+pub struct Day{nn};
+
+impl Solution for Day{nn} {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part_two(input).into())
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+"#;
+
+const TEST_TEMPLATE: &str = r#"//! Day {day} against its example, `cargo test` also checks it against `answers.toml`
+use aoc::day{nn};
+
+const EXAMPLE: &str = include_str!("../input/{nn}/example.txt");
+
+#[test]
+#[ignore = "the example answers haven't been filled in yet"]
+fn example() {
+    let input = day{nn}::parse(EXAMPLE).unwrap();
+    assert_eq!(day{nn}::part_one(&input).to_string(), "");
+    assert_eq!(day{nn}::part_two(&input).to_string(), "");
+}
+"#;
+
+const ANSWERS_TEMPLATE: &str = r#"# Fill these in once they're known, quote anything that isn't a number
+[example]
+# part1 =
+# part2 =
+
+[real]
+# part1 =
+# part2 =
+"#;
+
+const REGISTRY_TEMPLATE: &str = r#"    Puzzle {
+        day: {day},
+        title: {title_lit},
+        solution: &crate::day{nn}::Day{nn},
+    },"#;
+
+/// Create the files for a new day under the crate at `root` and register it, giving every path
+/// that was created or changed
+pub fn new_day(root: &Path, day: u8, title: &str) -> std::io::Result<Vec<PathBuf>> {
+    let fill = |template: &str| {
+        template
+            .replace("{day}", &day.to_string())
+            .replace("{nn}", &format!("{day:02}"))
+            .replace("{title}", title)
+            // Quoted and escaped, ready to go in Rust source
+            .replace("{title_lit}", &format!("{title:?}"))
+    };
+    let input_dir = day_dir(&root.join(input::DEFAULT_DIR), day);
+    let files = [
+        (
+            root.join("src").join(format!("day{day:02}.rs")),
+            fill(MODULE_TEMPLATE),
+        ),
+        (
+            root.join("tests").join(format!("day{day:02}.rs")),
+            fill(TEST_TEMPLATE),
+        ),
+        (input_dir.join("example.txt"), String::new()),
+        (input_dir.join("real.txt"), String::new()),
+        (
+            input_dir.join(crate::answers::FILE_NAME),
+            ANSWERS_TEMPLATE.to_string(),
+        ),
+    ];
+    if let Some((existing, _)) = files.iter().find(|(path, _)| path.exists()) {
+        return Err(already_exists(existing));
+    }
+
+    let lib_path = root.join("src").join("lib.rs");
+    let lib = std::fs::read_to_string(&lib_path).map_err(|err| in_file(err, &lib_path))?;
+    let module = format!("pub mod day{day:02};");
+    if lib.lines().any(|line| line == module) {
+        return Err(Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} already has day {day}", lib_path.display()),
+        ));
+    }
+    let lib = insert_module(&lib, &module).ok_or_else(|| not_found(&lib_path, "day modules"))?;
+
+    let registry_path = root.join("src").join("solution.rs");
+    let registry =
+        std::fs::read_to_string(&registry_path).map_err(|err| in_file(err, &registry_path))?;
+    let registry = insert_puzzle(&registry, day, &fill(REGISTRY_TEMPLATE))
+        .ok_or_else(|| not_found(&registry_path, "PUZZLES registry"))?;
+
+    let mut changed = Vec::new();
+    for (path, contents) in files {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all(contents.as_bytes()))
+            .map_err(|err| in_file(err, &path))?;
+        changed.push(path);
+    }
+    std::fs::write(&lib_path, lib)?;
+    changed.push(lib_path);
+    std::fs::write(&registry_path, registry)?;
+    changed.push(registry_path);
+    Ok(changed)
+}
+
+/// Add the module line in order amongst the other days
+fn insert_module(lib: &str, module: &str) -> Option<String> {
+    let mut lines = lib.lines().collect::<Vec<_>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("pub mod day") && line.ends_with(';'))
+        .collect::<Vec<_>>();
+    let (first, _) = days.first()?;
+    let at = days
+        .iter()
+        .rev()
+        .find(|(_, &line)| line < module)
+        .map_or(*first, |(idx, _)| idx + 1);
+    lines.insert(at, module);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Add the entry to the registry in order of day
+fn insert_puzzle(registry: &str, day: u8, entry: &str) -> Option<String> {
+    let mut lines = registry.lines().collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("static PUZZLES"))?;
+    let end = start + lines[start..].iter().position(|&line| line == "];")?;
+    let later = (start..end).find(|&idx| {
+        lines[idx]
+            .trim()
+            .strip_prefix("day: ")
+            .and_then(|rest| rest.strip_suffix(','))
+            .and_then(|number| number.parse::<u8>().ok())
+            .map_or(false, |other| other > day)
+    });
+    // Entries open with `Puzzle {` on the line before their day
+    let at = later.map_or(end, |idx| idx - 1);
+    lines.insert(at, entry);
+    Some(lines.join("\n") + "\n")
+}
+
+fn already_exists(path: &Path) -> Error {
+    Error::new(
+        ErrorKind::AlreadyExists,
+        format!("{} already exists, not overwriting it", path.display()),
+    )
+}
+
+fn not_found(path: &Path, what: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("Couldn't find the {what} in {}", path.display()),
+    )
+}
@@ -0,0 +1,313 @@
+//! The shared shape of a day's solution and the registry of days the runner knows about.
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+use crate::alloc::{self, Usage};
+pub use crate::error::Result;
+use crate::gen::Rng;
+use crate::{Error, Part};
+
+/// The answer to one part of a puzzle, they're all printed in the end so a string is good enough
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer(String);
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+macro_rules! answer_from {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Answer(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(i32, u32, u64, usize, String, &str);
+
+/// A day's solution, parsing is kept separate from solving so the parsed input can be shared
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+    fn part_one(&self, input: &Self::Input) -> Result<Answer>;
+    fn part_two(&self, input: &Self::Input) -> Result<Answer>;
+
+    /// Named ways of solving each part, for days with a naive and a fast version worth comparing,
+    /// none by default so `part_one` and `part_two` are the only ones
+    fn implementations(&self) -> Vec<Implementation<Self::Input>> {
+        Vec::new()
+    }
+
+    /// A random input for `aoc gen`, `size` is roughly how many of the day's things it holds
+    fn generate(&self, _rng: &mut Rng, _size: usize) -> Result<String> {
+        Err(Error::Unsupported(
+            "There's no input generator for this day yet".to_string(),
+        ))
+    }
+}
+
+/// One way of solving one part, chosen on the command line with `--impl <name>`
+pub struct Implementation<I> {
+    pub name: &'static str,
+    pub part: Part,
+    pub solve: fn(&I) -> Result<Answer>,
+}
+
+/// Parsed input with the type erased, only the solution that produced it knows what's inside
+pub type Parsed = Box<dyn Any>;
+
+/// Object safe version of [`Solution`] so days with different input types can live in one registry
+pub trait DynSolution {
+    fn parse(&self, input: &str) -> Result<Parsed>;
+    fn part_one(&self, input: &Parsed) -> Result<Answer>;
+    fn part_two(&self, input: &Parsed) -> Result<Answer>;
+
+    /// Names of the implementations of `part`, see [`Solution::implementations`]
+    fn implementations(&self, part: Part) -> Vec<&'static str>;
+
+    /// Solve `part` with the implementation called `name`
+    fn solve_with(&self, part: Part, name: &str, input: &Parsed) -> Result<Answer>;
+
+    /// See [`Solution::generate`]
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<String>;
+
+    fn solve(&self, part: Part, input: &Parsed) -> Result<Answer> {
+        match part {
+            Part::One => self.part_one(input),
+            Part::Two => self.part_two(input),
+        }
+    }
+
+    /// Solve `part` with the named implementation, or the usual one when there's no name
+    fn solve_named(&self, part: Part, name: Option<&str>, input: &Parsed) -> Result<Answer> {
+        match name {
+            Some(name) => self.solve_with(part, name, input),
+            None => self.solve(part, input),
+        }
+    }
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Parsed> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn part_one(&self, input: &Parsed) -> Result<Answer> {
+        Solution::part_one(self, downcast::<S>(input)?)
+    }
+
+    fn part_two(&self, input: &Parsed) -> Result<Answer> {
+        Solution::part_two(self, downcast::<S>(input)?)
+    }
+
+    fn implementations(&self, part: Part) -> Vec<&'static str> {
+        Solution::implementations(self)
+            .into_iter()
+            .filter(|implementation| implementation.part == part)
+            .map(|implementation| implementation.name)
+            .collect()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<String> {
+        Solution::generate(self, rng, size)
+    }
+
+    fn solve_with(&self, part: Part, name: &str, input: &Parsed) -> Result<Answer> {
+        let implementations = Solution::implementations(self);
+        let implementation = implementations
+            .iter()
+            .find(|implementation| implementation.part == part && implementation.name == name);
+        match implementation {
+            Some(implementation) => (implementation.solve)(downcast::<S>(input)?),
+            None => {
+                let names = DynSolution::implementations(self, part);
+                Err(Error::Unsupported(if names.is_empty() {
+                    format!("Part {part} has no implementations to choose from")
+                } else {
+                    format!(
+                        "Part {part} has no implementation called {name:?}, try one of {}",
+                        names.join(", ")
+                    )
+                }))
+            }
+        }
+    }
+}
+
+fn downcast<S: Solution>(input: &Parsed) -> Result<&S::Input>
+where
+    S::Input: 'static,
+{
+    input.downcast_ref::<S::Input>().ok_or(Error::Internal(
+        "Parsed input belongs to a different solution",
+    ))
+}
+
+/// A registered day
+pub struct Puzzle {
+    pub day: u8,
+    pub title: &'static str,
+    pub solution: &'static (dyn DynSolution + Sync),
+}
+
+/// The answer to a part along with how long it took to solve
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    /// What solving allocated, when allocations are being counted
+    pub alloc: Option<Usage>,
+}
+
+/// What every implementation of a part made of the same input
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    pub part: Part,
+    /// The usual implementation comes first as `default`, errors are kept as their message
+    pub answers: Vec<(&'static str, std::result::Result<Answer, String>)>,
+}
+
+impl Comparison {
+    /// Whether every implementation came up with the same answer, or the same error
+    pub fn agrees(&self) -> bool {
+        self.answers.windows(2).all(|pair| pair[0].1 == pair[1].1)
+    }
+}
+
+/// Result of parsing an input once and solving some parts of it
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub parse_elapsed: Duration,
+    pub parse_alloc: Option<Usage>,
+    pub outcomes: Vec<Outcome>,
+}
+
+impl Puzzle {
+    /// Parse the input once and solve each of the parts in turn, timing every step
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<Run> {
+        self.run_with(input, parts, None)
+    }
+
+    /// [`Puzzle::run`] with the named implementation of each part instead of the usual one
+    pub fn run_with(
+        &self,
+        input: &str,
+        parts: &[Part],
+        implementation: Option<&str>,
+    ) -> Result<Run> {
+        let start = Instant::now();
+        let (parsed, parse_alloc) = alloc::measure(|| self.solution.parse(input));
+        let parse_elapsed = start.elapsed();
+        let parsed = parsed?;
+
+        let mut outcomes = Vec::with_capacity(parts.len());
+        for &part in parts {
+            let start = Instant::now();
+            let (answer, alloc) =
+                alloc::measure(|| self.solution.solve_named(part, implementation, &parsed));
+            let elapsed = start.elapsed();
+            outcomes.push(Outcome {
+                part,
+                answer: answer?,
+                elapsed,
+                alloc,
+            });
+        }
+        Ok(Run {
+            parse_elapsed,
+            parse_alloc,
+            outcomes,
+        })
+    }
+
+    /// Solve each part that has named implementations with every one of them, along with the
+    /// usual one, so they can be checked against each other
+    pub fn compare(&self, input: &str) -> Result<Vec<Comparison>> {
+        let parsed = self.solution.parse(input)?;
+        let mut comparisons = Vec::new();
+        for part in [Part::One, Part::Two] {
+            let names = self.solution.implementations(part);
+            if names.is_empty() {
+                continue;
+            }
+            let mut answers = vec![(
+                "default",
+                self.solution
+                    .solve(part, &parsed)
+                    .map_err(|err| err.to_string()),
+            )];
+            answers.extend(names.into_iter().map(|name| {
+                let answer = self.solution.solve_with(part, name, &parsed);
+                (name, answer.map_err(|err| err.to_string()))
+            }));
+            comparisons.push(Comparison { part, answers });
+        }
+        Ok(comparisons)
+    }
+}
+
+/// Every solved day, `aoc new` adds new days here and to `lib.rs` so keep the layout as it is
+static PUZZLES: &[Puzzle] = &[
+    Puzzle {
+        day: 1,
+        title: "Calorie Counting",
+        solution: &crate::day01::Day01,
+    },
+    Puzzle {
+        day: 2,
+        title: "Rock Paper Scissors",
+        solution: &crate::day02::Day02,
+    },
+    Puzzle {
+        day: 3,
+        title: "Rucksack Reorganization",
+        solution: &crate::day03::Day03,
+    },
+    Puzzle {
+        day: 4,
+        title: "Camp Cleanup",
+        solution: &crate::day04::Day04,
+    },
+    Puzzle {
+        day: 5,
+        title: "Supply Stacks",
+        solution: &crate::day05::Day05,
+    },
+    Puzzle {
+        day: 6,
+        title: "Tuning Trouble",
+        solution: &crate::day06::Day06,
+    },
+    Puzzle {
+        day: 7,
+        title: "No Space Left On Device",
+        solution: &crate::day07::Day07,
+    },
+    Puzzle {
+        day: 8,
+        title: "Treetop Tree House",
+        solution: &crate::day08::Day08,
+    },
+];
+
+/// Every registered day, in order
+pub fn registry() -> &'static [Puzzle] {
+    PUZZLES
+}
+
+pub fn find(day: u8) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|puzzle| puzzle.day == day)
+}
@@ -0,0 +1,132 @@
+//! What's been done for each day of the calendar, behind `aoc status`
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::answers::{Answers, Check};
+use crate::input::{self, Source};
+use crate::solution::{self, Puzzle};
+use crate::{Part, Parts, Result};
+
+/// Every day of the calendar
+pub const DAYS: RangeInclusive<u8> = 1..=25;
+
+/// Where one day is up to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayStatus {
+    pub day: u8,
+    /// The puzzle's title when there's a solution registered
+    pub title: Option<&'static str>,
+    /// How many examples there are, including the main one, leaving out empty files
+    pub examples: usize,
+    /// Whether the real input has been saved and isn't empty
+    pub real: bool,
+    /// How many answers are recorded, for both parts of every input
+    pub answers: usize,
+    /// Answers that came out as recorded
+    pub passed: usize,
+    /// Answers that didn't, along with inputs the solution gave an error for
+    pub failed: usize,
+    /// Why the first of those inputs couldn't be read or solved
+    pub error: Option<String>,
+    /// How long parsing and solving both parts took on the real input, or on the main example when
+    /// there's no real one, in the run just done to check the answers
+    pub elapsed: Option<Duration>,
+}
+
+impl DayStatus {
+    /// A solution exists, its checks all pass and there's at least one of them
+    pub fn done(&self) -> bool {
+        self.title.is_some() && self.failed == 0 && self.passed > 0
+    }
+}
+
+/// Look at the registry and the day's inputs and answers, running the solution on every input
+/// there is to check it against the answers
+pub fn status(input_dir: &Path, day: u8) -> Result<DayStatus> {
+    let puzzle = solution::find(day);
+    let answers = Answers::load(input_dir, day)?;
+    let mut sources = input::examples(input_dir, day)?;
+    sources.push(Source::Real);
+
+    let recorded = sources
+        .iter()
+        .flat_map(|source| [Part::One, Part::Two].map(|part| answers.expected(source, part)))
+        .filter(Option::is_some)
+        .count();
+    let inputs = sources
+        .into_iter()
+        .filter_map(|source| {
+            let path = source.path(input_dir, day)?;
+            let len = std::fs::metadata(path).ok()?.len();
+            (len > 0).then_some(source)
+        })
+        .collect::<Vec<_>>();
+
+    let mut status = DayStatus {
+        day,
+        title: puzzle.map(|puzzle| puzzle.title),
+        examples: inputs
+            .iter()
+            .filter(|source| **source != Source::Real)
+            .count(),
+        real: inputs.contains(&Source::Real),
+        answers: recorded,
+        passed: 0,
+        failed: 0,
+        error: None,
+        elapsed: None,
+    };
+    if let Some(puzzle) = puzzle {
+        let timed = if status.real {
+            Source::Real
+        } else {
+            Source::Example
+        };
+        for source in &inputs {
+            let elapsed = check(puzzle, input_dir, &answers, source, &mut status);
+            if *source == timed {
+                status.elapsed = elapsed;
+            }
+        }
+    }
+    Ok(status)
+}
+
+/// Run both parts on one input, adding how it went to the status and giving how long it took
+fn check(
+    puzzle: &Puzzle,
+    input_dir: &Path,
+    answers: &Answers,
+    source: &Source,
+    status: &mut DayStatus,
+) -> Option<Duration> {
+    let run = source
+        .read(input_dir, puzzle.day)
+        .map_err(crate::Error::from)
+        .and_then(|input| puzzle.run(&input, Parts::Both.parts()));
+    let run = match run {
+        Ok(run) => run,
+        Err(err) => {
+            status.failed += 1;
+            if status.error.is_none() {
+                let err = err.with_file(source.path(input_dir, puzzle.day).as_deref());
+                status.error = Some(format!("{source}: {err}"));
+            }
+            return None;
+        }
+    };
+    for outcome in &run.outcomes {
+        match answers.check(source, outcome.part, &outcome.answer) {
+            Check::Pass => status.passed += 1,
+            Check::Fail(_) => status.failed += 1,
+            Check::Unknown => {}
+        }
+    }
+    let solving = run
+        .outcomes
+        .iter()
+        .map(|outcome| outcome.elapsed)
+        .sum::<Duration>();
+    Some(run.parse_elapsed + solving)
+}
@@ -0,0 +1,108 @@
+//! The tiny subset of TOML that `answers.toml` and `aoc.toml` need
+//!
+//! That's `[section]` headers, `key = value` lines with optionally quoted values, and `#` comment
+//! lines. Keys before the first header go in the section named `""`. Quoted values are TOML basic
+//! strings, escapes and all.
+use std::collections::BTreeMap;
+use std::str::Chars;
+
+use crate::ParseError;
+
+/// Values keyed by section then key
+pub type Table = BTreeMap<String, BTreeMap<String, String>>;
+
+pub fn parse(text: &str) -> Result<Table, ParseError> {
+    let mut table = Table::new();
+    let mut current = String::new();
+    for (idx, raw) in text.lines().enumerate() {
+        let syntax = || ParseError::whole_line("Unrecognised line", raw).on_line(idx + 1);
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            current = name.trim().to_string();
+            table.entry(current.clone()).or_default();
+        } else if let Some((key, value)) = line.split_once('=') {
+            let value = value.trim();
+            let value = match value.strip_prefix('"') {
+                Some(quoted) => unquote(quoted).ok_or_else(|| {
+                    // The value runs to the end of the line, bar trailing whitespace
+                    let offset = raw.trim_end().len() - value.len();
+                    ParseError::at("Badly quoted value", raw, offset, value.len()).on_line(idx + 1)
+                })?,
+                None => value.to_string(),
+            };
+            table
+                .entry(current.clone())
+                .or_default()
+                .insert(key.trim().to_string(), value);
+        } else {
+            return Err(syntax());
+        }
+    }
+    Ok(table)
+}
+
+/// An error about a key [`parse`] found in `text`, pointing at the first line that sets it
+pub fn key_error(message: &str, text: &str, key: &str) -> ParseError {
+    let found = text.lines().enumerate().find_map(|(idx, line)| {
+        let (name, _) = line.split_once('=')?;
+        let offset = name.len() - name.trim_start().len();
+        (name.trim() == key)
+            .then(|| ParseError::at(message, line, offset, key.len()).on_line(idx + 1))
+    });
+    found.unwrap_or_else(|| ParseError::whole_line(message, key))
+}
+
+/// The value as a basic string, quoted and escaped so that [`parse`] reads it back unchanged
+pub fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04X}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// What's inside a basic string with the escapes undone, given everything after its opening quote.
+/// Nothing may follow the closing quote.
+fn unquote(quoted: &str) -> Option<String> {
+    let mut value = String::new();
+    let mut chars = quoted.chars();
+    loop {
+        match chars.next()? {
+            '"' => return chars.as_str().is_empty().then_some(value),
+            '\\' => value.push(match chars.next()? {
+                'b' => '\u{8}',
+                't' => '\t',
+                'n' => '\n',
+                'f' => '\u{c}',
+                'r' => '\r',
+                '"' => '"',
+                '\\' => '\\',
+                'u' => unicode(&mut chars, 4)?,
+                'U' => unicode(&mut chars, 8)?,
+                _ => return None,
+            }),
+            c => value.push(c),
+        }
+    }
+}
+
+/// The character for the next `digits` hex digits
+fn unicode(chars: &mut Chars, digits: usize) -> Option<char> {
+    let hex = (0..digits)
+        .map(|_| chars.next())
+        .collect::<Option<String>>()?;
+    char::from_u32(u32::from_str_radix(&hex, 16).ok()?)
+}
@@ -0,0 +1,47 @@
+//! Optional chatter about what a day is doing along the way, on stderr so answers stay clean
+//!
+//! Days call [`verbose!`](crate::verbose) for the main steps and [`trace!`](crate::trace) for
+//! the fine detail, which print nothing until [`set_level`] turns them on. `-v` and `-vv` on the
+//! command line do that.
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Quiet,
+    /// The main steps, `-v`
+    Verbose,
+    /// Everything, `-vv`
+    Trace,
+}
+
+/// Set how much to print, from the number of times `-v` was given
+pub fn set_level(verbosity: u8) {
+    LEVEL.store(verbosity, Ordering::Relaxed);
+}
+
+/// Whether messages at this level are printed
+pub fn enabled(level: Level) -> bool {
+    LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+/// Print to stderr with `-v` or more, the arguments are only evaluated when it will be printed
+#[macro_export]
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Verbose) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Print to stderr with `-vv`, the arguments are only evaluated when it will be printed
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,50 @@
+//! Noticing when files change by polling their modification times, which works anywhere without
+//! needing the OS to send notifications
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// What's known about a file, `None` when it doesn't exist
+type Stamp = Option<(SystemTime, u64)>;
+
+/// Watches a fixed set of files, which don't have to exist yet
+#[derive(Debug, Clone)]
+pub struct Watcher {
+    files: Vec<(PathBuf, Stamp)>,
+}
+
+impl Watcher {
+    /// Start watching, the files as they are now count as unchanged
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let stamp = stamp(&path);
+                (path, stamp)
+            })
+            .collect();
+        Self { files }
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.iter().map(|(path, _)| path.as_path())
+    }
+
+    /// The files that were created, changed or removed since the last look
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        for (path, last) in &mut self.files {
+            let now = stamp(path);
+            if now != *last {
+                *last = now;
+                changed.push(path.clone());
+            }
+        }
+        changed
+    }
+}
+
+/// Modification time and length, since a quick edit can land within the same timestamp tick
+fn stamp(path: &Path) -> Stamp {
+    let meta = std::fs::metadata(path).ok()?;
+    Some((meta.modified().ok()?, meta.len()))
+}
@@ -0,0 +1,25 @@
+//! The counting allocator, installed for this test binary only
+use aoc::alloc::{self, Counting};
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// One test so nothing else allocates on another thread while it's measuring
+#[test]
+fn measures_allocations_bytes_and_peak() {
+    drop(vec![0u8; 16]);
+    assert!(alloc::installed());
+
+    let (_, usage) = alloc::measure(|| {
+        let first = vec![0u8; 100];
+        drop(first);
+        let mut second = Vec::with_capacity(60);
+        second.push(1u8);
+        second.len()
+    });
+    let usage = usage.unwrap();
+    assert_eq!((usage.allocations, usage.bytes, usage.peak), (2, 160, 100));
+
+    let (_, usage) = alloc::measure(|| (1..=10).sum::<u32>());
+    assert_eq!(usage.unwrap(), alloc::Usage::default());
+}
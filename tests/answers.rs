@@ -0,0 +1,45 @@
+//! Recording answers in `answers.toml` and reading them back
+use aoc::answers::Answers;
+use aoc::{Part, Source};
+
+use common::ScratchDir;
+
+mod common;
+
+#[test]
+fn recorded_answers_read_back_unchanged() {
+    let dir = ScratchDir::new("answers");
+    let recorded = [
+        (Source::Example, Part::One, "-12"),
+        (Source::Example, Part::Two, "CMZ"),
+        (Source::Real, Part::One, r#"say "hi" \o/"#),
+        (Source::Real, Part::Two, "tab\there\nthen a line \u{7} é"),
+    ];
+    let mut answers = Answers::load(&dir, 5).unwrap();
+    for (source, part, answer) in &recorded {
+        assert!(answers.record(&dir, 5, source, *part, answer).unwrap());
+    }
+
+    let text = std::fs::read_to_string(dir.join("05/answers.toml")).unwrap();
+    assert!(text.contains(r#"part1 = "say \"hi\" \\o/""#), "{text}");
+    let loaded = Answers::load(&dir, 5).unwrap();
+    assert_eq!(loaded, answers);
+    for (source, part, answer) in &recorded {
+        assert_eq!(loaded.expected(source, *part), Some(*answer), "{text}");
+    }
+}
+
+#[test]
+fn quoted_values_must_be_closed_and_escaped_properly() {
+    let answers = "[real]\npart1 = \"a \\u00e9 \\\" b\"\n"
+        .parse::<Answers>()
+        .unwrap();
+    assert_eq!(answers.expected(&Source::Real, Part::One), Some("a é \" b"));
+
+    for bad in ["\"open", "\"a\" b", "\"\\q\"", "\"\\u12\""] {
+        let err = format!("[real]\npart1 = {bad}\n")
+            .parse::<Answers>()
+            .unwrap_err();
+        assert_eq!(err.line, 2, "{bad}");
+    }
+}
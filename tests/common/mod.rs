@@ -0,0 +1,30 @@
+//! Helpers shared by the integration tests
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+/// An empty directory for one test, removed again when dropped, even when the test panics
+pub struct ScratchDir(PathBuf);
+
+impl ScratchDir {
+    /// `name` keeps tests that run at the same time out of each other's way
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+}
+
+impl Deref for ScratchDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
@@ -0,0 +1,112 @@
+//! Pulling examples and answers out of a saved puzzle page
+use std::path::Path;
+
+use aoc::answers::Answers;
+use aoc::extract::{self, Page, PartPage};
+use aoc::{Part, Source};
+
+use common::ScratchDir;
+
+mod common;
+
+fn page() -> Page {
+    let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/puzzle.html");
+    Page::parse(&std::fs::read_to_string(fixture).unwrap())
+}
+
+#[test]
+fn examples_and_answers_are_found() {
+    let page = page();
+    assert_eq!(page.title.as_deref(), Some("Tuning Trouble"));
+    assert_eq!(
+        page.parts,
+        [
+            PartPage {
+                example: Some("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n".to_string()),
+                example_answer: Some("7".to_string()),
+                answer: Some("1356".to_string()),
+            },
+            PartPage {
+                example: None,
+                example_answer: Some("19".to_string()),
+                answer: Some("2564".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn description_becomes_markdown() {
+    let markdown = page().markdown;
+    assert!(
+        markdown.starts_with("## --- Day 6: Tuning Trouble ---\n\n"),
+        "{markdown}"
+    );
+    assert!(
+        markdown.contains("\n```\nmjqjpqmgbljsphdztnvjfqwrcgsmlb\n```\n"),
+        "{markdown}"
+    );
+    assert!(
+        markdown.contains("`7` & nothing **sooner**. See [about](/2022/about)."),
+        "{markdown}"
+    );
+    assert!(
+        markdown.contains("Your puzzle answer was `2564`."),
+        "{markdown}"
+    );
+}
+
+#[test]
+fn extract_fills_in_only_what_is_missing() {
+    let input_dir = ScratchDir::new("extract");
+    std::fs::create_dir_all(input_dir.join("06")).unwrap();
+    std::fs::write(
+        input_dir.join("06/answers.toml"),
+        "# Kept\n[real]\npart1 = 1\n",
+    )
+    .unwrap();
+
+    extract::extract(&page(), &input_dir, 6).unwrap();
+    let example = std::fs::read_to_string(input_dir.join("06/examples/part1.txt")).unwrap();
+    assert_eq!(example, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n");
+    assert!(input_dir.join("06/puzzle.md").exists());
+
+    let manifest = std::fs::read_to_string(input_dir.join("06/answers.toml")).unwrap();
+    assert!(
+        manifest.starts_with("# Kept\n[real]\npart1 = 1\npart2 = 2564\n"),
+        "{manifest}"
+    );
+    let answers = Answers::load(&input_dir, 6).unwrap();
+    let part1 = Source::NamedExample("part1".to_string());
+    assert_eq!(answers.expected(&part1, Part::One), Some("7"));
+    assert_eq!(answers.expected(&part1, Part::Two), Some("19"));
+
+    // Nothing left to do the second time around
+    let done = extract::extract(&page(), &input_dir, 6).unwrap();
+    assert_eq!(done.len(), 1, "{done:?}");
+}
+
+#[test]
+fn answers_are_not_recorded_against_a_different_example() {
+    let input_dir = ScratchDir::new("extract-different");
+    std::fs::create_dir_all(input_dir.join("06/examples")).unwrap();
+    std::fs::write(input_dir.join("06/examples/part1.txt"), "abcdefg\n").unwrap();
+
+    let done = extract::extract(&page(), &input_dir, 6).unwrap();
+    assert!(done[0].starts_with("Left "), "{done:?}");
+    let example = std::fs::read_to_string(input_dir.join("06/examples/part1.txt")).unwrap();
+    assert_eq!(example, "abcdefg\n");
+    let manifest = std::fs::read_to_string(input_dir.join("06/answers.toml")).unwrap();
+    assert!(!manifest.contains("[examples.part1]"), "{manifest}");
+    let answers = Answers::load(&input_dir, 6).unwrap();
+    assert_eq!(answers.expected(&Source::Real, Part::One), Some("1356"));
+}
+
+#[test]
+fn unreadable_examples_are_errors_not_overwritten() {
+    let input_dir = ScratchDir::new("extract-unreadable");
+    std::fs::create_dir_all(input_dir.join("06/examples/part1.txt")).unwrap();
+    let err = extract::extract(&page(), &input_dir, 6).unwrap_err();
+    assert!(err.to_string().contains("part1.txt"), "{err}");
+    assert!(input_dir.join("06/examples/part1.txt").is_dir());
+}
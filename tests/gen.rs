@@ -0,0 +1,39 @@
+//! The input generators, which must be repeatable and only ever produce inputs with answers
+use aoc::gen::Rng;
+use aoc::{solution, Parts};
+
+#[test]
+fn same_seed_same_input() {
+    for puzzle in solution::registry() {
+        let generate = |seed| puzzle.solution.generate(&mut Rng::new(seed), 20).unwrap();
+        assert_eq!(generate(7), generate(7), "Day {}", puzzle.day);
+        assert_ne!(generate(7), generate(8), "Day {}", puzzle.day);
+    }
+}
+
+#[test]
+fn generated_inputs_have_answers() {
+    let mut failures = Vec::new();
+    for puzzle in solution::registry() {
+        for seed in 0..50 {
+            let size = seed as usize;
+            let input = puzzle.solution.generate(&mut Rng::new(seed), size).unwrap();
+            if let Err(err) = puzzle.run(&input, Parts::Both.parts()) {
+                failures.push(format!(
+                    "Day {:02} seed {seed} size {size}: {err}",
+                    puzzle.day
+                ));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn ranges_stay_in_bounds() {
+    let mut rng = Rng::new(1);
+    let picks = (0..1000).map(|_| rng.range(3..=5)).collect::<Vec<_>>();
+    assert!(picks.iter().all(|pick| (3..=5).contains(pick)));
+    assert!([3, 4, 5].iter().all(|value| picks.contains(value)));
+    assert_eq!(rng.range(9..=9), 9);
+}
@@ -0,0 +1,88 @@
+//! Every registered day against the answers recorded in `input/NN/answers.toml`
+//!
+//! The main `example.txt` must exist and have answers for both parts. Extra examples in
+//! `examples/` often only illustrate one part, so they're checked for whichever parts have answers,
+//! and real inputs are checked when they're present and have answers recorded.
+use std::path::Path;
+
+use aoc::answers::{Answers, Check};
+use aoc::input::{self, Trailing};
+use aoc::{solution, Parts, Source};
+
+fn input_dir() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/input"))
+}
+
+/// Run both parts of a day on one input, describing anything that doesn't match
+fn check_input(puzzle: &solution::Puzzle, answers: &Answers, source: &Source) -> Vec<String> {
+    let day = puzzle.day;
+    let input = match source.read(input_dir(), day) {
+        Ok(input) => input,
+        Err(_) if *source == Source::Real => return Vec::new(),
+        Err(err) => return vec![format!("Day {day:02} ({source}): {err}")],
+    };
+    let run = match puzzle.run(&input, Parts::Both.parts()) {
+        Ok(run) => run,
+        Err(err) => return vec![format!("Day {day:02} ({source}): {err}")],
+    };
+    run.outcomes
+        .into_iter()
+        .filter_map(
+            |outcome| match answers.check(source, outcome.part, &outcome.answer) {
+                Check::Pass => None,
+                Check::Unknown if *source != Source::Example => None,
+                check => Some(format!(
+                    "Day {day:02} part {} ({source}): got {}, {check}",
+                    outcome.part, outcome.answer
+                )),
+            },
+        )
+        .collect()
+}
+
+#[test]
+fn golden_answers() {
+    let mut failures = Vec::new();
+    for puzzle in solution::registry() {
+        let answers = Answers::load(input_dir(), puzzle.day).unwrap();
+        let mut sources = input::examples(input_dir(), puzzle.day).unwrap();
+        sources.push(Source::Real);
+        for source in sources {
+            failures.extend(check_input(puzzle, &answers, &source));
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn registry_is_ordered_without_duplicates() {
+    let days = solution::registry()
+        .iter()
+        .map(|puzzle| puzzle.day)
+        .collect::<Vec<_>>();
+    assert!(days.windows(2).all(|pair| pair[0] < pair[1]), "{days:?}");
+}
+
+#[test]
+fn windows_line_endings_and_byte_order_marks_are_invisible() {
+    for puzzle in solution::registry() {
+        let example = Source::Example.read(input_dir(), puzzle.day).unwrap();
+        let windows = format!("\u{feff}{}", example.replace('\n', "\r\n"));
+        let normalised = input::normalise(&windows, Trailing::Keep).text;
+        let answers = |input: &str| {
+            puzzle
+                .run(input, Parts::Both.parts())
+                .unwrap()
+                .outcomes
+                .into_iter()
+                .map(|outcome| outcome.answer)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            answers(&normalised),
+            answers(&example),
+            "Day {}",
+            puzzle.day
+        );
+    }
+}
@@ -0,0 +1,40 @@
+//! The shared grid, mostly the parts day 8 doesn't exercise
+use aoc::grid::{Direction, Grid};
+
+fn letters() -> Grid<char> {
+    Grid::parse("abc\ndef\n", Ok::<_, &str>).unwrap()
+}
+
+#[test]
+fn parse_rejects_ragged_rows_and_bad_cells() {
+    let err = Grid::parse("abc\nde\n", Ok::<_, &str>).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 1));
+    let err = Grid::parse("12\n3x\n", |c| c.to_digit(10).ok_or("Not a digit")).unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+}
+
+#[test]
+fn checked_access_and_neighbours() {
+    let mut grid = letters();
+    assert_eq!(grid.get((2, 1)), Some(&'f'));
+    assert_eq!(grid.get((3, 0)), None);
+    *grid.get_mut((0, 0)).unwrap() = 'A';
+    let neighbours = |cells: Vec<_>| cells.into_iter().map(|(_, &c)| c).collect::<String>();
+    assert_eq!(neighbours(grid.neighbours((0, 0)).collect()), "bd");
+    assert_eq!(neighbours(grid.neighbours8((1, 1)).collect()), "bcfdA");
+    assert_eq!(
+        neighbours(grid.ray((2, 1), Direction::West).collect()),
+        "ed"
+    );
+}
+
+#[test]
+fn rows_columns_and_rotation() {
+    let grid = letters();
+    assert_eq!(grid.column(1).collect::<String>(), "be");
+    assert_eq!(grid.rows().count(), 2);
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+    assert_eq!(grid.rotate_anticlockwise().to_string(), "cf\nbe\nad\n");
+    assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+}
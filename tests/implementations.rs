@@ -0,0 +1,63 @@
+//! Every named implementation of a part must agree with the usual one, on the recorded inputs
+//! and on lots of generated ones
+use std::path::Path;
+
+use aoc::gen::Rng;
+use aoc::input;
+use aoc::solution::{self, Puzzle};
+use aoc::Source;
+
+fn input_dir() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/input"))
+}
+
+/// Describe every comparison on `input` that didn't agree
+fn disagreements(puzzle: &Puzzle, input: &str, description: &str) -> Vec<String> {
+    let day = puzzle.day;
+    let comparisons = match puzzle.compare(input) {
+        Ok(comparisons) => comparisons,
+        Err(err) => return vec![format!("Day {day:02} ({description}): {err}")],
+    };
+    comparisons
+        .into_iter()
+        .filter(|comparison| !comparison.agrees())
+        .map(|comparison| {
+            format!(
+                "Day {day:02} part {} ({description}): {:?}",
+                comparison.part, comparison.answers
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn implementations_agree_on_recorded_inputs() {
+    let mut failures = Vec::new();
+    for puzzle in solution::registry() {
+        let mut sources = input::examples(input_dir(), puzzle.day).unwrap();
+        sources.push(Source::Real);
+        for source in sources {
+            if let Ok(input) = source.read(input_dir(), puzzle.day) {
+                failures.extend(disagreements(puzzle, &input, &source.to_string()));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn implementations_agree_on_generated_inputs() {
+    let mut failures = Vec::new();
+    for puzzle in solution::registry() {
+        for seed in 0..200 {
+            let size = (seed % 40) as usize;
+            let input = puzzle.solution.generate(&mut Rng::new(seed), size).unwrap();
+            failures.extend(disagreements(
+                puzzle,
+                &input,
+                &format!("seed {seed}, size {size}"),
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
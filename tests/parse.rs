@@ -0,0 +1,63 @@
+//! The shared parsing helpers, mostly that their errors point at the right place
+use aoc::parse::{self, Line};
+
+fn line(text: &str) -> Line<'_> {
+    Line { number: 3, text }
+}
+
+#[test]
+fn groups_split_on_any_run_of_blank_lines() {
+    let groups = parse::groups("1\n2\n\n  \n3\n\n")
+        .map(|group| group.iter().map(|line| line.number).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(groups, [vec![1, 2], vec![5]]);
+}
+
+#[test]
+fn integers_report_the_bad_number() {
+    let line = line("2-4,6-x");
+    let [one, two] = line.whole().split(",").unwrap();
+    assert_eq!(one.integers::<u32, 2>("-").unwrap(), [2, 4]);
+    let err = two.integers::<u32, 2>("-").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (3, 7, "x"));
+}
+
+#[test]
+fn template_captures_and_mismatches() {
+    let template = "move {} from {} to {}";
+    let captures = line("move 12 from 3 to 4").template::<3>(template).unwrap();
+    assert_eq!(captures.map(|capture| capture.text), ["12", "3", "4"]);
+    assert_eq!(captures.map(|capture| capture.offset), [5, 13, 18]);
+    let err = line("move 12 from 3 too 4")
+        .template::<3>(template)
+        .unwrap_err();
+    assert_eq!(err.message, "Expected \" to \"");
+    let err = line("move  from 3 to 4")
+        .template::<3>(template)
+        .unwrap_err();
+    assert_eq!((err.column, err.message.as_str()), (6, "Expected a value"));
+}
+
+#[test]
+fn columns_are_read_top_to_bottom() {
+    let rows = parse::lines("    [D]\n[N] [C]\n").collect::<Vec<_>>();
+    let columns = parse::columns(&rows, 4)
+        .into_iter()
+        .map(|column| column.into_iter().map(|cell| cell.text).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(columns, [vec!["    ", "[N] "], vec!["[D]", "[C]"]]);
+}
+
+#[test]
+fn errors_point_at_the_offending_column() {
+    let err = line("  é 12x ").whole().trim().split::<2>(" ").unwrap()[1]
+        .integer::<u32>()
+        .unwrap_err();
+    assert_eq!((err.column, err.text.as_str()), (5, "12x"));
+
+    // Settings aren't slices of their line once parsed, the key is still found where it was set
+    let err = "# Settings\n  sesion = \"cafe\"\n"
+        .parse::<aoc::answers::Answers>()
+        .unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "sesion"));
+}
@@ -0,0 +1,177 @@
+//! Talking to the puzzle site, with a local stand-in playing the site
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::thread;
+
+use aoc::config::Config;
+use aoc::remote::{self, Fetched, History, Submitted, Verdict};
+use aoc::{Answer, Part};
+
+use common::ScratchDir;
+
+mod common;
+
+/// Serve each of the canned `(status, body)` responses to one request in turn, sending back the
+/// requests that were received
+fn mock_site(responses: Vec<(u16, &'static str)>) -> (Config, mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let endpoint = format!("http://{}/2022", listener.local_addr().unwrap());
+    let (sender, requests) = mpsc::channel();
+    thread::spawn(move || {
+        for (status, body) in responses {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(length) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body_in = vec![0; content_length];
+            reader.read_exact(&mut body_in).unwrap();
+            request.push_str(&String::from_utf8(body_in).unwrap());
+            sender.send(request).unwrap();
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 {status} Whatever\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+    let config = Config {
+        session: Some("cafe".to_string()),
+        endpoint,
+        ..Config::default()
+    };
+    (config, requests)
+}
+
+#[test]
+fn fetch_downloads_once_then_uses_the_cache() {
+    let input_dir = ScratchDir::new("fetch");
+    let (config, requests) = mock_site(vec![(200, "1\n2\n3\n")]);
+
+    let fetched = remote::fetch(&config, &input_dir, 9).unwrap();
+    let path = input_dir.join("09").join("real.txt");
+    assert_eq!(fetched, Fetched::Downloaded(path.clone()));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+    let request = requests.recv().unwrap();
+    assert!(
+        request.starts_with("GET /2022/day/9/input HTTP/1.1\r\n"),
+        "{request}"
+    );
+    assert!(request.contains("Cookie: session=cafe\r\n"), "{request}");
+
+    // The stand-in only answers once, so this would fail if it tried again
+    assert_eq!(
+        remote::fetch(&config, &input_dir, 9).unwrap(),
+        Fetched::Cached(path)
+    );
+}
+
+#[test]
+fn fetch_reports_failures_without_caching_them() {
+    let input_dir = ScratchDir::new("fetch-fail");
+    let (config, _requests) = mock_site(vec![(400, "Please log in")]);
+    let err = remote::fetch(&config, &input_dir, 9).unwrap_err();
+    assert!(err.to_string().contains("400: Please log in"), "{err}");
+    assert!(!input_dir.join("09").join("real.txt").exists());
+}
+
+#[test]
+fn verdicts_are_read_from_the_response() {
+    let verdict = |body: &str| Verdict::from_response(body);
+    assert_eq!(
+        verdict("<p>That's the right answer! You are one gold star closer."),
+        Verdict::Right
+    );
+    assert_eq!(
+        verdict("<p>That's not the right answer; your answer is too high."),
+        Verdict::TooHigh
+    );
+    assert_eq!(
+        verdict("<p>That's not the right answer; your answer is too low."),
+        Verdict::TooLow
+    );
+    assert_eq!(
+        verdict("<p>That's not the right answer. If you're stuck..."),
+        Verdict::Wrong
+    );
+    assert_eq!(
+        verdict("<p>You gave an answer too recently; you have 37s left to wait."),
+        Verdict::RateLimited(Some("37s".to_string()))
+    );
+}
+
+#[test]
+fn submit_keeps_history_and_refuses_what_it_rules_out() {
+    let input_dir = ScratchDir::new("submit");
+    let (config, requests) = mock_site(vec![
+        (
+            200,
+            "<p>That's not the right answer; your answer is too high.</p>",
+        ),
+        (
+            200,
+            "<p>That's not the right answer; your answer is too low.</p>",
+        ),
+        (200, "<p>That's the right answer!</p>"),
+    ]);
+    let submit = |answer: u32| {
+        remote::submit(&config, &input_dir, 9, Part::One, &Answer::from(answer)).unwrap()
+    };
+
+    assert_eq!(submit(500), Submitted::Judged(Verdict::TooHigh));
+    let request = requests.recv().unwrap();
+    assert!(
+        request.starts_with("POST /2022/day/9/answer HTTP/1.1\r\n"),
+        "{request}"
+    );
+    assert!(request.ends_with("\r\n\r\nlevel=1&answer=500"), "{request}");
+
+    assert!(matches!(submit(500), Submitted::Refused(_)));
+    assert!(matches!(submit(600), Submitted::Refused(_)));
+    assert_eq!(submit(100), Submitted::Judged(Verdict::TooLow));
+    assert!(matches!(submit(50), Submitted::Refused(_)));
+    assert_eq!(submit(300), Submitted::Judged(Verdict::Right));
+    assert!(matches!(submit(300), Submitted::Refused(_)));
+
+    let history = History::load(&input_dir, 9).unwrap();
+    let answers = history
+        .submissions
+        .iter()
+        .map(|sub| (sub.answer.as_str(), sub.verdict.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        answers,
+        [
+            ("500", Verdict::TooHigh),
+            ("100", Verdict::TooLow),
+            ("300", Verdict::Right)
+        ]
+    );
+}
+
+#[test]
+fn missing_session_points_at_the_config_that_was_loaded() {
+    let dir = ScratchDir::new("config");
+    let path = dir.join("other.toml");
+    std::fs::write(&path, "endpoint = \"http://127.0.0.1:1/2022\"\n").unwrap();
+    let config = Config::load(&path).unwrap();
+    assert_eq!(config.file, path);
+    // A session in the environment would mean there's nothing to complain about
+    if std::env::var("AOC_SESSION").is_err() {
+        let err = config.session().unwrap_err();
+        assert!(err.to_string().contains("other.toml"), "{err}");
+    }
+}
@@ -0,0 +1,186 @@
+//! Every day's model written back out as puzzle text must parse into the same model again
+//!
+//! The models are random and go further than real inputs do, like more than nine stacks of
+//! crates, negative calories or sections that run backwards.
+use std::fmt::Debug;
+
+use aoc::day02::{InsLeft, InsRight, Round};
+use aoc::day07::fs::Fs;
+use aoc::gen::{self, Rng};
+use aoc::grid::Grid;
+use aoc::{day01, day02, day03, day04, day05, day06, day07, day08};
+
+const ROUNDS: u64 = 200;
+
+/// Round trip `ROUNDS` random models, reporting the text of the first one that comes back changed
+fn round_trip<T, E>(
+    mut random: impl FnMut(&mut Rng) -> T,
+    to_text: impl Fn(&T) -> String,
+    parse: impl Fn(&str) -> Result<T, E>,
+) where
+    T: PartialEq + Debug,
+    E: Debug,
+{
+    for seed in 0..ROUNDS {
+        let model = random(&mut Rng::new(seed));
+        let text = to_text(&model);
+        let parsed = parse(&text).unwrap_or_else(|err| panic!("Seed {seed}: {err:?}\n{text}"));
+        assert_eq!(parsed, model, "Seed {seed}\n{text}");
+    }
+}
+
+/// Up to `most` of something
+fn some<T>(rng: &mut Rng, most: usize, mut item: impl FnMut(&mut Rng) -> T) -> Vec<T> {
+    (0..rng.range(0..=most)).map(|_| item(rng)).collect()
+}
+
+#[test]
+fn day01_calories() {
+    round_trip(
+        |rng| some(rng, 20, |rng| rng.range(0..=2_000_000) as i32 - 1_000_000),
+        |calories| day01::to_text(calories),
+        day01::parse,
+    );
+}
+
+#[test]
+fn day02_strategy_guide() {
+    round_trip(
+        |rng| {
+            some(rng, 20, |rng| {
+                Round(
+                    *rng.pick(&[InsLeft::A, InsLeft::B, InsLeft::C]),
+                    *rng.pick(&[InsRight::X, InsRight::Y, InsRight::Z]),
+                )
+            })
+        },
+        |rounds| day02::to_text(rounds),
+        day02::parse,
+    );
+}
+
+#[test]
+fn day03_rucksacks() {
+    round_trip(
+        |rng| {
+            some(rng, 10, |rng| {
+                let len = 2 * rng.range(0..=10);
+                (0..len).map(|_| rng.range(1..=52) as u8).collect()
+            })
+        },
+        |rucksacks| day03::to_text(rucksacks),
+        day03::parse,
+    );
+}
+
+#[test]
+fn day04_section_pairs() {
+    round_trip(
+        |rng| {
+            some(rng, 20, |rng| {
+                let mut sections = || rng.range(0..=1000) as u32..=rng.range(0..=1000) as u32;
+                (sections(), sections())
+            })
+        },
+        |pairs| day04::to_text(pairs),
+        day04::parse,
+    );
+}
+
+#[test]
+fn day05_stacks_and_procedure() {
+    let letters = format!("{}{}", gen::UPPERCASE, gen::LOWERCASE);
+    round_trip(
+        |rng| {
+            let stacks = some(rng, 20, |rng| some(rng, 12, |rng| rng.char(&letters)));
+            let commands = if stacks.is_empty() {
+                Vec::new()
+            } else {
+                some(rng, 10, |rng| {
+                    let len = stacks.len();
+                    (rng.range(0..=50), rng.below(len), rng.below(len))
+                })
+            };
+            (stacks, commands)
+        },
+        day05::to_text,
+        day05::parse_input,
+    );
+}
+
+#[test]
+fn day06_signal() {
+    round_trip(
+        |rng| {
+            some(rng, 40, |rng| rng.char(gen::LOWERCASE))
+                .into_iter()
+                .collect::<String>()
+        },
+        |signal| day06::to_text(signal),
+        |text| Ok::<_, ()>(day06::parse(text)),
+    );
+}
+
+/// A name that isn't already in the directory, including dots and digits where they're allowed
+fn entry_name(rng: &mut Rng, fs: &Fs, dir: aoc::day07::fs::Handle) -> String {
+    loop {
+        let mut name = some(rng, 7, |rng| rng.char("abcxyz0189._-"))
+            .into_iter()
+            .collect::<String>();
+        name.insert(0, rng.char(gen::LOWERCASE));
+        if fs.get_child(dir, &name).is_err() {
+            return name;
+        }
+    }
+}
+
+#[test]
+fn day07_file_system() {
+    round_trip(
+        |rng| {
+            let mut fs = Fs::new();
+            let mut dirs = vec![fs.root()];
+            for _ in 0..rng.range(0..=30) {
+                let parent = *rng.pick(&dirs);
+                let name = entry_name(rng, &fs, parent);
+                let handle = if rng.one_in(3) {
+                    let dir = fs.create_dir(&name).unwrap();
+                    dirs.push(dir);
+                    dir
+                } else {
+                    fs.create_file(&name, rng.range(0..=1_000_000_000) as u64)
+                        .unwrap()
+                };
+                fs.move_to(handle, parent).unwrap();
+            }
+            FsModel(fs)
+        },
+        |model| day07::to_text(&model.0),
+        |text| day07::parse(text).map(FsModel),
+    );
+}
+
+/// `Fs` can't be printed, so failures show the text it was parsed from instead
+#[derive(PartialEq)]
+struct FsModel(Fs);
+
+impl Debug for FsModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", day07::to_text(&self.0))
+    }
+}
+
+#[test]
+fn day08_forest() {
+    round_trip(
+        |rng| {
+            let (width, height) = match rng.range(0..=12) {
+                0 => (0, 0),
+                width => (width, rng.range(1..=12)),
+            };
+            Grid::filled(width, height, 0).map(|_| rng.below(10) as u8)
+        },
+        day08::to_text,
+        day08::parse,
+    );
+}
@@ -0,0 +1,54 @@
+//! `aoc new` against a scratch copy of the files it edits
+use std::path::Path;
+
+use aoc::scaffold;
+
+use common::ScratchDir;
+
+mod common;
+
+/// A scratch crate root holding copies of the real `lib.rs` and `solution.rs`
+fn scratch_root(name: &str) -> ScratchDir {
+    let root = ScratchDir::new(name);
+    std::fs::create_dir_all(root.join("src")).unwrap();
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    for file in ["lib.rs", "solution.rs"] {
+        std::fs::copy(src.join(file), root.join("src").join(file)).unwrap();
+    }
+    root
+}
+
+#[test]
+fn new_day_is_wired_in_and_never_overwritten() {
+    let root = scratch_root("scaffold");
+    let changed = scaffold::new_day(&root, 9, "Rope Bridge").unwrap();
+    assert!(changed.iter().all(|path| path.exists()), "{changed:?}");
+
+    let lib = std::fs::read_to_string(root.join("src/lib.rs")).unwrap();
+    assert!(lib.contains("pub mod day08;\npub mod day09;\n"), "{lib}");
+    let registry = std::fs::read_to_string(root.join("src/solution.rs")).unwrap();
+    assert!(registry.contains("title: \"Rope Bridge\""), "{registry}");
+    assert!(
+        registry.contains("&crate::day09::Day09,\n    },\n];"),
+        "{registry}"
+    );
+    let module = std::fs::read_to_string(root.join("src/day09.rs")).unwrap();
+    assert!(module.starts_with("//! # Day 9: Rope Bridge\n"), "{module}");
+
+    std::fs::write(root.join("src/day09.rs"), "solved").unwrap();
+    let err = scaffold::new_day(&root, 9, "Rope Bridge").unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+    let module = std::fs::read_to_string(root.join("src/day09.rs")).unwrap();
+    assert_eq!(module, "solved");
+}
+
+#[test]
+fn titles_are_escaped_in_the_registry() {
+    let root = scratch_root("scaffold-quoted");
+    scaffold::new_day(&root, 10, r#"Cathode-Ray "Tube" \o/"#).unwrap();
+    let registry = std::fs::read_to_string(root.join("src/solution.rs")).unwrap();
+    assert!(
+        registry.contains(r#"title: "Cathode-Ray \"Tube\" \\o/","#),
+        "{registry}"
+    );
+}
@@ -0,0 +1,46 @@
+//! `aoc status` against a scratch input directory
+use std::path::Path;
+
+use aoc::status;
+
+use common::ScratchDir;
+
+mod common;
+
+#[test]
+fn status_counts_inputs_answers_and_checks() {
+    let dir = ScratchDir::new("status");
+    let example = Path::new(env!("CARGO_MANIFEST_DIR")).join("input/01/example.txt");
+    std::fs::create_dir_all(dir.join("01")).unwrap();
+    std::fs::copy(example, dir.join("01/example.txt")).unwrap();
+    std::fs::write(dir.join("01/real.txt"), "").unwrap();
+    std::fs::write(
+        dir.join("01/answers.toml"),
+        "[example]\npart1 = 24000\npart2 = 1\n\n[real]\npart1 = 5\n",
+    )
+    .unwrap();
+
+    let day01 = status::status(&dir, 1).unwrap();
+    assert_eq!(day01.title, Some("Calorie Counting"));
+    // The empty real input doesn't count, but its answer does
+    assert_eq!((day01.examples, day01.real, day01.answers), (1, false, 3));
+    assert_eq!((day01.passed, day01.failed), (1, 1));
+    assert!(day01.elapsed.is_some());
+    assert_eq!(day01.error, None);
+    assert!(!day01.done());
+
+    // Only the main example or the real input is timed, and the first error is kept
+    std::fs::create_dir_all(dir.join("02/examples")).unwrap();
+    std::fs::write(dir.join("02/examples/other.txt"), "A Q\n").unwrap();
+    let day02 = status::status(&dir, 2).unwrap();
+    assert_eq!((day02.examples, day02.real), (1, false));
+    assert_eq!(day02.elapsed, None);
+    assert_eq!(day02.failed, 1);
+    let error = day02.error.unwrap();
+    assert!(error.contains("other.txt:1:"), "{error}");
+
+    let day25 = status::status(&dir, 25).unwrap();
+    assert_eq!(day25.title, None);
+    assert_eq!((day25.examples, day25.answers, day25.passed), (0, 0, 0));
+    assert_eq!(day25.elapsed, None);
+}
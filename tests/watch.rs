@@ -0,0 +1,24 @@
+//! Polling files for changes
+use aoc::watch::Watcher;
+
+use common::ScratchDir;
+
+mod common;
+
+#[test]
+fn creation_edits_and_removal_are_noticed_once() {
+    let dir = ScratchDir::new("watch");
+    let path = dir.join("input.txt");
+    let mut watcher = Watcher::new([path.clone()]);
+    assert!(watcher.changed().is_empty());
+
+    std::fs::write(&path, "1").unwrap();
+    assert_eq!(watcher.changed(), std::slice::from_ref(&path));
+    assert!(watcher.changed().is_empty());
+
+    std::fs::write(&path, "12").unwrap();
+    assert_eq!(watcher.changed(), std::slice::from_ref(&path));
+
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(watcher.changed(), [path]);
+}